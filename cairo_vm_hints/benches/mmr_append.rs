@@ -0,0 +1,92 @@
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use eth_essentials_cairo_vm_hints::mmr::{position, CompactMmr, Hasher, Keccak, Mmr, Poseidon};
+use starknet_types_core::felt::Felt;
+
+const LEAF_COUNTS: [usize; 3] = [1_000, 10_000, 100_000];
+
+fn poseidon_leaves(count: usize) -> Vec<Felt> {
+    (0..count).map(|i| Felt::from(i + 1)).collect()
+}
+
+fn keccak_leaves(count: usize) -> Vec<[u8; 32]> {
+    (0..count)
+        .map(|i| {
+            let mut leaf = [0u8; 32];
+            leaf[24..].copy_from_slice(&(i as u64 + 1).to_be_bytes());
+            leaf
+        })
+        .collect()
+}
+
+/// The append of `Mmr` before it located siblings by position: every peak is read, then the new nodes are
+/// collected into a fresh vector. Kept as the baseline of the `Mmr` benchmark.
+fn append_collecting_peaks<H: Hasher>(hasher: &H, nodes: &mut Vec<H::Digest>, leaf_count: usize, element: H::Digest) {
+    let peaks = position::get_peaks(nodes.len())
+        .expect("Invalid node count")
+        .into_iter()
+        .map(|pos| nodes[pos].clone())
+        .collect::<Vec<_>>();
+    let mut new_nodes = vec![element.clone()];
+    let mut last_node = element;
+    for peak in peaks.iter().rev().take(leaf_count.trailing_ones() as usize) {
+        last_node = hasher.hash(peak, &last_node);
+        new_nodes.push(last_node.clone());
+    }
+    nodes.extend(new_nodes);
+}
+
+fn bench_append<H: Hasher>(c: &mut Criterion, name: &str, leaves: fn(usize) -> Vec<H::Digest>) {
+    let mut group = c.benchmark_group(format!("mmr_append/{}", name));
+    for count in LEAF_COUNTS {
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::new("Mmr", count), &leaves(count), |b, leaves| {
+            b.iter_batched(
+                || leaves.clone(),
+                |leaves| {
+                    let mut mmr = Mmr::<H>::new();
+                    for leaf in leaves {
+                        mmr.append(leaf).unwrap();
+                    }
+                    mmr
+                },
+                BatchSize::LargeInput,
+            )
+        });
+        group.bench_with_input(BenchmarkId::new("Mmr (collecting peaks)", count), &leaves(count), |b, leaves| {
+            b.iter_batched(
+                || leaves.clone(),
+                |leaves| {
+                    let hasher = H::new();
+                    let mut nodes = vec![];
+                    for (leaf_count, leaf) in leaves.into_iter().enumerate() {
+                        append_collecting_peaks(&hasher, &mut nodes, leaf_count, leaf);
+                    }
+                    nodes
+                },
+                BatchSize::LargeInput,
+            )
+        });
+        group.bench_with_input(BenchmarkId::new("CompactMmr", count), &leaves(count), |b, leaves| {
+            b.iter_batched(
+                || leaves.clone(),
+                |leaves| {
+                    let mut mmr = CompactMmr::<H>::new();
+                    for leaf in leaves {
+                        mmr.append(leaf);
+                    }
+                    mmr
+                },
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn mmr_append(c: &mut Criterion) {
+    bench_append::<Poseidon>(c, "poseidon", poseidon_leaves);
+    bench_append::<Keccak>(c, "keccak", keccak_leaves);
+}
+
+criterion_group!(benches, mmr_append);
+criterion_main!(benches);
@@ -0,0 +1,61 @@
+#![deny(warnings)]
+#![forbid(unsafe_code)]
+
+use clap::{Parser, ValueHint};
+use eth_essentials_cairo_vm_hints::mpt::get_proof::decode_hex;
+use eth_essentials_cairo_vm_hints::mpt::{GetProofError, GetProofResponse};
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// Converts a saved `eth_getProof` response into program input for the MPT verification programs.
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    #[clap(value_parser, value_hint=ValueHint::FilePath)]
+    response_file: PathBuf,
+    /// State root of the block the proof was fetched at, as 32 hex-encoded bytes.
+    #[clap(long = "state_root", value_parser = parse_state_root)]
+    state_root: [u8; 32],
+    /// Where to write the program input, stdout if omitted.
+    #[clap(long = "output", value_hint=ValueHint::FilePath)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Debug, Error)]
+enum Error {
+    #[error("Invalid arguments")]
+    Cli(#[from] clap::Error),
+    #[error("Failed to interact with the file system")]
+    IO(#[from] std::io::Error),
+    #[error(transparent)]
+    GetProof(#[from] GetProofError),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+fn parse_state_root(value: &str) -> Result<[u8; 32], String> {
+    let bytes = decode_hex(value).map_err(|error| error.to_string())?;
+    bytes
+        .try_into()
+        .map_err(|bytes: Vec<u8>| format!("expected 32 bytes, found {}", bytes.len()))
+}
+
+fn run(args: impl Iterator<Item = String>) -> Result<(), Error> {
+    let args = Args::try_parse_from(args)?;
+
+    let response = GetProofResponse::from_file(&args.response_file)?;
+    let json = serde_json::to_string_pretty(&response.program_input(args.state_root))?;
+    match args.output {
+        Some(path) => std::fs::write(path, json)?,
+        None => println!("{json}"),
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<(), Error> {
+    match run(std::env::args()) {
+        Err(Error::Cli(err)) => err.exit(),
+        other => other,
+    }
+}
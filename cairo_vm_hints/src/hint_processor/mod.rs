@@ -1,28 +1,111 @@
-use crate::hints;
 use cairo_vm::{
     hint_processor::{
         builtin_hint_processor::builtin_hint_processor_definition::{BuiltinHintProcessor, HintFunc, HintProcessorData},
-        hint_processor_definition::HintExtension,
-        hint_processor_definition::HintProcessorLogic,
+        hint_processor_definition::{HintExtension, HintProcessorLogic, HintReference},
     },
+    serde::deserialize_program::{ApTracking, ProgramJson},
     types::exec_scope::ExecutionScopes,
-    vm::{errors::hint_errors::HintError, runners::cairo_runner::ResourceTracker, vm_core::VirtualMachine},
+    vm::{
+        errors::{hint_errors::HintError, vm_errors::VirtualMachineError},
+        runners::cairo_runner::{ResourceTracker, RunResources},
+        vm_core::VirtualMachine,
+    },
     Felt252,
 };
 use starknet_types_core::felt::Felt;
 use std::collections::HashMap;
+use std::time::Instant;
 use std::{any::Any, rc::Rc};
 
-#[derive(Default)]
-pub struct CustomHintProcessor;
+use crate::hints::HintFunction;
+
+pub mod builtin_hints;
+pub mod program_input;
+pub mod registry;
+pub mod statistics;
+pub mod unknown_hints;
+
+pub use program_input::{ProgramInput, PROGRAM_INPUT};
+pub use registry::{HintModule, HintRegistry, HintRegistryError};
+pub use statistics::{HintStatistics, HintStatisticsReport};
+pub use unknown_hints::{UnknownHint, UnknownHintReport};
+
+/// Hint data compiled by `CustomHintProcessor::compile_hint` for a hint of its registry, along with the
+/// implementation of the hint, so that it is looked up once per program instead of at every run.
+pub struct CompiledHint {
+    pub hint_data: HintProcessorData,
+    pub hint: HintFunction,
+}
+
+pub struct CustomHintProcessor {
+    registry: HintRegistry,
+    program_input: Option<Rc<ProgramInput>>,
+}
+
+impl Default for CustomHintProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl CustomHintProcessor {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            registry: HintRegistry::new(),
+            program_input: None,
+        }
+    }
+
+    /// Exposes `program_input` to the hints, see `ProgramInput::from_scopes`.
+    pub fn set_program_input(&mut self, program_input: ProgramInput) {
+        self.program_input = Some(Rc::new(program_input));
+    }
+
+    pub fn registry(&self) -> &HintRegistry {
+        &self.registry
+    }
+
+    /// Runs the hints of `module` along with those already registered, see `HintRegistry::register_module`.
+    pub fn register_module(&mut self, module: &dyn HintModule) -> Result<(), HintRegistryError> {
+        self.registry.register_module(module)
+    }
+
+    fn run_compiled(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        compiled: &CompiledHint,
+        constants: &HashMap<String, Felt252>,
+    ) -> Result<(), HintError> {
+        // Hints only see the variables of the current scope, so the input is added to each scope they run in.
+        if let Some(program_input) = &self.program_input {
+            if ProgramInput::from_scopes(exec_scopes).is_err() {
+                exec_scopes.insert_value(PROGRAM_INPUT, program_input.clone());
+            }
+        }
+
+        (compiled.hint)(vm, exec_scopes, &compiled.hint_data, constants)
     }
 }
 
 impl HintProcessorLogic for CustomHintProcessor {
+    /// Resolves the hints of the registry to a `CompiledHint`. Other hints are compiled as by the builtin
+    /// hint processor, and fail with `UnknownHint` when run.
+    fn compile_hint(
+        &self,
+        hint_code: &str,
+        ap_tracking_data: &ApTracking,
+        reference_ids: &HashMap<String, usize>,
+        references: &[HintReference],
+    ) -> Result<Box<dyn Any>, VirtualMachineError> {
+        let hint_data = BuiltinHintProcessor::new_empty().compile_hint(hint_code, ap_tracking_data, reference_ids, references)?;
+        let Some(hint) = self.registry.get(hint_code) else {
+            return Ok(hint_data);
+        };
+        let hint_data = *hint_data.downcast::<HintProcessorData>().map_err(|_| VirtualMachineError::Unexpected)?;
+        Ok(Box::new(CompiledHint { hint_data, hint }))
+    }
+
     fn execute_hint(
         &mut self,
         vm: &mut VirtualMachine,
@@ -30,9 +113,13 @@ impl HintProcessorLogic for CustomHintProcessor {
         hint_data: &Box<dyn Any>,
         constants: &HashMap<String, Felt252>,
     ) -> Result<(), HintError> {
-        let hint_data = hint_data.downcast_ref::<HintProcessorData>().ok_or(HintError::WrongHintData)?;
-
-        hints::run_hint(vm, exec_scopes, hint_data, constants)
+        match hint_data.downcast_ref::<CompiledHint>() {
+            Some(compiled) => self.run_compiled(vm, exec_scopes, compiled, constants),
+            None => {
+                let hint_data = hint_data.downcast_ref::<HintProcessorData>().ok_or(HintError::WrongHintData)?;
+                Err(HintError::UnknownHint(hint_data.code.clone().into_boxed_str()))
+            }
+        }
     }
 }
 
@@ -41,6 +128,8 @@ impl ResourceTracker for CustomHintProcessor {}
 pub struct ExtendedHintProcessor {
     custom_hint_processor: CustomHintProcessor,
     builtin_hint_processor: BuiltinHintProcessor,
+    run_resources: RunResources,
+    statistics: Option<HintStatistics>,
 }
 
 impl Default for ExtendedHintProcessor {
@@ -52,17 +141,107 @@ impl Default for ExtendedHintProcessor {
 impl ExtendedHintProcessor {
     pub fn new() -> Self {
         Self {
-            custom_hint_processor: CustomHintProcessor {},
+            custom_hint_processor: CustomHintProcessor::new(),
             builtin_hint_processor: BuiltinHintProcessor::new_empty(),
+            run_resources: RunResources::default(),
+            statistics: None,
         }
     }
 
     pub fn add_hint(&mut self, hint_code: String, hint_func: Rc<HintFunc>) {
         self.builtin_hint_processor.extra_hints.insert(hint_code, hint_func);
     }
+
+    /// Runs the hints of `module` along with those of the crate. They take precedence over the hints of
+    /// the builtin hint processor and over those added with `add_hint`.
+    pub fn register_module(&mut self, module: &dyn HintModule) -> Result<(), HintRegistryError> {
+        self.custom_hint_processor.register_module(module)
+    }
+
+    pub fn set_program_input(&mut self, program_input: ProgramInput) {
+        self.custom_hint_processor.set_program_input(program_input);
+    }
+
+    /// Limits the number of steps of the run. Cairo PIEs must be run with the number of steps they were built with.
+    pub fn set_run_resources(&mut self, run_resources: RunResources) {
+        self.run_resources = run_resources;
+    }
+
+    /// Stops the run after `max_steps` steps, with `VirtualMachineError::UnfinishedExecution`.
+    pub fn set_max_steps(&mut self, max_steps: usize) {
+        self.set_run_resources(RunResources::new(max_steps));
+    }
+
+    /// Whether the run used all the steps it was given.
+    pub fn steps_exhausted(&self) -> bool {
+        self.run_resources.consumed()
+    }
+
+    /// Counts the executions of each hint and their time, at the cost of timing every hint.
+    pub fn enable_statistics(&mut self) {
+        self.statistics.get_or_insert_with(HintStatistics::default);
+    }
+
+    pub fn statistics(&self) -> Option<&HintStatistics> {
+        self.statistics.as_ref()
+    }
+
+    /// The statistics of the hints run so far, with the registered hints that never ran. `None` unless
+    /// statistics are enabled.
+    pub fn statistics_report(&self) -> Option<HintStatisticsReport> {
+        let registered = self
+            .custom_hint_processor
+            .registry()
+            .codes()
+            .chain(self.builtin_hint_processor.extra_hints.keys().map(String::as_str));
+        Some(self.statistics.as_ref()?.report(registered))
+    }
+
+    /// Whether one of the hint processors implements the hint `code`.
+    pub fn supports(&self, code: &str) -> bool {
+        self.custom_hint_processor.registry().get(code).is_some()
+            || self.builtin_hint_processor.extra_hints.contains_key(code)
+            || builtin_hints::is_builtin_hint(code)
+    }
+
+    /// The hints of `program` that no hint processor implements, to report them all before running it.
+    pub fn unknown_hints(&self, program: &ProgramJson) -> UnknownHintReport {
+        UnknownHintReport::new(program, |code| self.supports(code))
+    }
+
+    /// Runs the hints compiled to a `CompiledHint` directly, and the others with the builtin hint processor.
+    fn run_hint(
+        &mut self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        hint_data: &Box<dyn Any>,
+        constants: &HashMap<String, Felt>,
+    ) -> Result<HintExtension, HintError> {
+        match hint_data.downcast_ref::<CompiledHint>() {
+            Some(compiled) => {
+                self.custom_hint_processor.run_compiled(vm, exec_scopes, compiled, constants)?;
+                Ok(HintExtension::default())
+            }
+            None => self.builtin_hint_processor.execute_hint_extensive(vm, exec_scopes, hint_data, constants),
+        }
+    }
 }
 
 impl HintProcessorLogic for ExtendedHintProcessor {
+    /// Resolves each hint once per program: the hints of the registry compile to a `CompiledHint`, the
+    /// others are left to the builtin hint processor. Hints added by a `HintExtension` during the run are
+    /// compiled by the builtin hint processor, and thus run by it.
+    fn compile_hint(
+        &self,
+        hint_code: &str,
+        ap_tracking_data: &ApTracking,
+        reference_ids: &HashMap<String, usize>,
+        references: &[HintReference],
+    ) -> Result<Box<dyn Any>, VirtualMachineError> {
+        self.custom_hint_processor
+            .compile_hint(hint_code, ap_tracking_data, reference_ids, references)
+    }
+
     fn execute_hint(
         &mut self,
         _vm: &mut VirtualMachine,
@@ -80,15 +259,38 @@ impl HintProcessorLogic for ExtendedHintProcessor {
         hint_data: &Box<dyn Any>,
         constants: &HashMap<String, Felt>,
     ) -> Result<HintExtension, HintError> {
-        match self.custom_hint_processor.execute_hint_extensive(vm, exec_scopes, hint_data, constants) {
-            Err(HintError::UnknownHint(_)) => {}
-            result => {
-                return result;
-            }
+        if self.statistics.is_none() {
+            return self.run_hint(vm, exec_scopes, hint_data, constants);
         }
 
-        self.builtin_hint_processor.execute_hint_extensive(vm, exec_scopes, hint_data, constants)
+        let start = Instant::now();
+        let result = self.run_hint(vm, exec_scopes, hint_data, constants);
+        let elapsed = start.elapsed();
+        let hint_data = match hint_data.downcast_ref::<CompiledHint>() {
+            Some(compiled) => Some(&compiled.hint_data),
+            None => hint_data.downcast_ref::<HintProcessorData>(),
+        };
+        if let (Some(statistics), Some(hint_data)) = (&mut self.statistics, hint_data) {
+            statistics.record(&hint_data.code, elapsed);
+        }
+        result
     }
 }
 
-impl ResourceTracker for ExtendedHintProcessor {}
+impl ResourceTracker for ExtendedHintProcessor {
+    fn consumed(&self) -> bool {
+        self.run_resources.consumed()
+    }
+
+    fn consume_step(&mut self) {
+        self.run_resources.consume_step();
+    }
+
+    fn get_n_steps(&self) -> Option<usize> {
+        self.run_resources.get_n_steps()
+    }
+
+    fn run_resources(&self) -> &RunResources {
+        &self.run_resources
+    }
+}
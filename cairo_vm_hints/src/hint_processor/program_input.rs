@@ -0,0 +1,119 @@
+use cairo_vm::{types::exec_scope::ExecutionScopes, vm::errors::hint_errors::HintError, Felt252};
+use num_bigint::BigUint;
+use num_traits::Num;
+use serde_json::Value;
+use std::rc::Rc;
+
+use crate::mpt::Uint256;
+
+/// Name of the scope variable holding the program input, as in the Python hints.
+pub const PROGRAM_INPUT: &str = "program_input";
+
+/// The JSON program input given to the runner, readable by every hint as `program_input`.
+///
+/// Felts are read from JSON numbers, or from decimal or `0x`-prefixed hex strings. A `Uint256` is read
+/// from a `[low, high]` pair, a `{"low": .., "high": ..}` object, or a single 256-bit number.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProgramInput(Value);
+
+impl ProgramInput {
+    pub fn new(value: Value) -> Self {
+        Self(value)
+    }
+
+    /// The program input of the running program.
+    pub fn from_scopes(exec_scopes: &ExecutionScopes) -> Result<Rc<ProgramInput>, HintError> {
+        exec_scopes.get::<Rc<ProgramInput>>(PROGRAM_INPUT)
+    }
+
+    pub fn value(&self) -> &Value {
+        &self.0
+    }
+
+    pub fn get(&self, key: &str) -> Result<&Value, HintError> {
+        self.0
+            .get(key)
+            .ok_or_else(|| HintError::CustomHint(format!("Missing program input: {key}").into_boxed_str()))
+    }
+
+    pub fn felt(&self, key: &str) -> Result<Felt252, HintError> {
+        parse_felt(self.get(key)?).ok_or_else(|| invalid(key, "a felt"))
+    }
+
+    pub fn felts(&self, key: &str) -> Result<Vec<Felt252>, HintError> {
+        parse_array(self.get(key)?, parse_felt).ok_or_else(|| invalid(key, "a felt array"))
+    }
+
+    /// An array of felt arrays, as written to a `felt**`.
+    pub fn nested_felts(&self, key: &str) -> Result<Vec<Vec<Felt252>>, HintError> {
+        parse_array(self.get(key)?, |value| parse_array(value, parse_felt)).ok_or_else(|| invalid(key, "an array of felt arrays"))
+    }
+
+    pub fn uint256(&self, key: &str) -> Result<Uint256, HintError> {
+        parse_uint256(self.get(key)?).ok_or_else(|| invalid(key, "a Uint256"))
+    }
+
+    pub fn uint256s(&self, key: &str) -> Result<Vec<Uint256>, HintError> {
+        parse_array(self.get(key)?, parse_uint256).ok_or_else(|| invalid(key, "a Uint256 array"))
+    }
+}
+
+impl From<Value> for ProgramInput {
+    fn from(value: Value) -> Self {
+        Self(value)
+    }
+}
+
+fn invalid(key: &str, expected: &str) -> HintError {
+    HintError::CustomHint(format!("Program input {key} is not {expected}").into_boxed_str())
+}
+
+fn parse_array<T>(value: &Value, parse: impl Fn(&Value) -> Option<T>) -> Option<Vec<T>> {
+    value.as_array()?.iter().map(parse).collect()
+}
+
+fn parse_felt(value: &Value) -> Option<Felt252> {
+    match value {
+        Value::Number(number) => Felt252::from_dec_str(&number.to_string()).ok(),
+        Value::String(string) if string.starts_with("0x") => Felt252::from_hex(string).ok(),
+        Value::String(string) => Felt252::from_dec_str(string).ok(),
+        _ => None,
+    }
+}
+
+fn parse_uint256(value: &Value) -> Option<Uint256> {
+    match value {
+        Value::Array(limbs) => match limbs.as_slice() {
+            [low, high] => Some(Uint256 {
+                low: parse_u128(low)?,
+                high: parse_u128(high)?,
+            }),
+            _ => None,
+        },
+        Value::Object(limbs) => Some(Uint256 {
+            low: parse_u128(limbs.get("low")?)?,
+            high: parse_u128(limbs.get("high")?)?,
+        }),
+        value => {
+            let bytes = parse_biguint(value)?.to_bytes_be();
+            let mut word = [0u8; 32];
+            word.get_mut(32usize.checked_sub(bytes.len())?..)?.copy_from_slice(&bytes);
+            Some(Uint256::from_be_bytes(word))
+        }
+    }
+}
+
+fn parse_u128(value: &Value) -> Option<u128> {
+    parse_biguint(value)?.try_into().ok()
+}
+
+fn parse_biguint(value: &Value) -> Option<BigUint> {
+    match value {
+        Value::Number(number) => BigUint::from_str_radix(&number.to_string(), 10).ok(),
+        Value::String(string) => match string.strip_prefix("0x") {
+            Some(digits) => BigUint::from_str_radix(digits, 16).ok(),
+            None => BigUint::from_str_radix(string, 10).ok(),
+        },
+        _ => None,
+    }
+}
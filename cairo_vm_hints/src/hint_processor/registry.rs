@@ -0,0 +1,241 @@
+use std::collections::{HashMap, HashSet};
+use thiserror::Error;
+
+use crate::hints::{EthEssentialsHints, HintFunction};
+
+/// A set of hints implemented in Rust, registered as a whole with `HintRegistry::register_module`.
+///
+/// Downstream crates implement it to run their own hints along with those of this crate, which are
+/// registered by `HintRegistry::new` as `EthEssentialsHints`.
+pub trait HintModule {
+    /// Names the module in conflict errors.
+    fn name(&self) -> &str;
+
+    /// The hint codes of the module, with their implementations.
+    fn hints(&self) -> Vec<(&'static str, HintFunction)>;
+
+    /// When modules register the same hint code, the one with the highest priority implements it.
+    fn priority(&self) -> i32 {
+        0
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum HintRegistryError {
+    #[error("Hint registered by both {registered} and {module} with priority {priority}: {code}")]
+    Conflict {
+        code: String,
+        registered: String,
+        module: String,
+        priority: i32,
+    },
+}
+
+#[derive(Clone)]
+struct RegisteredHint {
+    code: String,
+    hint: HintFunction,
+    module: String,
+    priority: i32,
+}
+
+/// Hint implementations keyed by hint code. Built once per processor, and looked up once for each hint of
+/// a program when the processor compiles it, instead of trying every hint module at each run.
+///
+/// Codes are matched on their `canonical_hint_code`, so that a hint reformatted in the Cairo sources
+/// still finds its implementation. Codes written exactly as registered skip the canonicalization.
+#[derive(Clone)]
+pub struct HintRegistry {
+    exact: HashMap<String, HintFunction>,
+    canonical: HashMap<String, RegisteredHint>,
+}
+
+impl Default for HintRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HintRegistry {
+    /// A registry holding every hint of the crate.
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        registry
+            .register_module(&EthEssentialsHints)
+            .expect("the hints of the crate have distinct codes");
+        registry
+    }
+
+    pub fn empty() -> Self {
+        Self {
+            exact: HashMap::new(),
+            canonical: HashMap::new(),
+        }
+    }
+
+    /// Registers the hints of `module`. A hint replaces one of lower priority registered for the same
+    /// canonical code, and is ignored if that one has a higher priority. Nothing is registered if a code
+    /// is already registered with the same priority, or appears twice in the module.
+    pub fn register_module(&mut self, module: &dyn HintModule) -> Result<(), HintRegistryError> {
+        let priority = module.priority();
+        let hints = module
+            .hints()
+            .into_iter()
+            .map(|(code, hint)| (canonical_hint_code(code), code, hint))
+            .collect::<Vec<_>>();
+
+        let mut canonical_codes = HashSet::new();
+        for (canonical_code, code, _) in &hints {
+            let conflicting_module = if canonical_codes.insert(canonical_code) {
+                self.canonical
+                    .get(canonical_code)
+                    .filter(|registered| registered.priority == priority)
+                    .map(|registered| registered.module.as_str())
+            } else {
+                Some(module.name())
+            };
+            if let Some(registered) = conflicting_module {
+                return Err(HintRegistryError::Conflict {
+                    code: code.to_string(),
+                    registered: registered.to_string(),
+                    module: module.name().to_string(),
+                    priority,
+                });
+            }
+        }
+
+        for (canonical_code, code, hint) in hints {
+            if let Some(registered) = self.canonical.get(&canonical_code) {
+                if registered.priority > priority {
+                    continue;
+                }
+                self.exact.remove(&registered.code);
+            }
+            self.exact.insert(code.to_string(), hint);
+            self.canonical.insert(
+                canonical_code,
+                RegisteredHint {
+                    code: code.to_string(),
+                    hint,
+                    module: module.name().to_string(),
+                    priority,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    /// The implementation of the hint `code`, matched exactly or else on its canonical form.
+    pub fn get(&self, code: &str) -> Option<HintFunction> {
+        match self.exact.get(code) {
+            Some(hint) => Some(*hint),
+            None => self.canonical.get(&canonical_hint_code(code)).map(|registered| registered.hint),
+        }
+    }
+
+    /// The codes of the registered hints, as registered.
+    pub fn codes(&self) -> impl Iterator<Item = &str> {
+        self.canonical.values().map(|registered| registered.code.as_str())
+    }
+
+    /// The name of the module implementing the hint `code`.
+    pub fn module(&self, code: &str) -> Option<&str> {
+        self.canonical
+            .get(&canonical_hint_code(code))
+            .map(|registered| registered.module.as_str())
+    }
+}
+
+/// Normalizes the formatting of hint code: line endings, blank lines, trailing whitespace, indentation
+/// and the whitespace within lines. Each block is indented by 4 spaces whatever its original width, and
+/// whitespace within a line is kept only where it separates two words or two string literals, as in
+/// `x if y else z`. String literals, including multi-line ones, and comments are kept as written.
+pub fn canonical_hint_code(code: &str) -> String {
+    let mut canonical = String::with_capacity(code.len());
+    let mut indentation_stack: Vec<usize> = Vec::new();
+    let mut string = None;
+    for line in code.lines() {
+        // The lines of a multi-line string are part of its value.
+        if string.is_some() {
+            canonical.push('\n');
+            canonicalize_line(line, &mut string, &mut canonical);
+            continue;
+        }
+
+        let content = line.trim();
+        if content.is_empty() {
+            continue;
+        }
+        let indentation = indentation_width(&line[..line.len() - line.trim_start().len()]);
+        while indentation_stack.last() > Some(&indentation) {
+            indentation_stack.pop();
+        }
+        if indentation_stack.last() < Some(&indentation) {
+            indentation_stack.push(indentation);
+        }
+
+        if !canonical.is_empty() {
+            canonical.push('\n');
+        }
+        canonical.push_str(&"    ".repeat(indentation_stack.len() - 1));
+        canonicalize_line(line.trim_start(), &mut string, &mut canonical);
+    }
+    canonical
+}
+
+/// Width of leading whitespace, tabs advancing to the next multiple of 8 as in Python.
+fn indentation_width(indentation: &str) -> usize {
+    indentation
+        .chars()
+        .fold(0, |width, char| if char == '\t' { (width / 8 + 1) * 8 } else { width + 1 })
+}
+
+/// Delimiters of Python string literals, triple quotes first.
+const STRING_DELIMITERS: [&str; 4] = ["'''", "\"\"\"", "'", "\""];
+
+/// Appends `line`, keeping string literals and comments as written. `string` holds the delimiter of the
+/// string literal open at the start of the line, if any, and at its end.
+fn canonicalize_line(line: &str, string: &mut Option<&'static str>, canonical: &mut String) {
+    let is_word = |char: char| char.is_alphanumeric() || char == '_';
+    let is_quote = |char: char| char == '\'' || char == '"';
+    let mut chars = line.char_indices();
+    let mut pending_space = false;
+    while let Some((position, char)) = chars.next() {
+        if let Some(delimiter) = *string {
+            if char == '\\' {
+                canonical.push(char);
+                canonical.extend(chars.next().map(|(_, escaped)| escaped));
+            } else if line[position..].starts_with(delimiter) {
+                canonical.push_str(delimiter);
+                chars.by_ref().take(delimiter.len() - 1).for_each(drop);
+                *string = None;
+            } else {
+                canonical.push(char);
+            }
+            continue;
+        }
+        if char.is_whitespace() {
+            pending_space = true;
+            continue;
+        }
+        if pending_space && (canonical.ends_with(is_word) && is_word(char) || canonical.ends_with(is_quote) && is_quote(char)) {
+            canonical.push(' ');
+        }
+        pending_space = false;
+        match char {
+            '#' => return canonical.push_str(&line[position..]),
+            '\'' | '"' => {
+                let delimiter = STRING_DELIMITERS
+                    .into_iter()
+                    .find(|delimiter| line[position..].starts_with(delimiter))
+                    .unwrap();
+                canonical.push_str(delimiter);
+                chars.by_ref().take(delimiter.len() - 1).for_each(drop);
+                *string = Some(delimiter);
+                continue;
+            }
+            _ => {}
+        }
+        canonical.push(char);
+    }
+}
@@ -0,0 +1,73 @@
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+use super::registry::canonical_hint_code;
+
+/// How many times a hint ran during a run, and for how long in total.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HintStats {
+    pub executions: u64,
+    pub total_time: Duration,
+}
+
+/// Execution counts and times of the hints run by a hint processor, keyed by hint code.
+#[derive(Debug, Clone, Default)]
+pub struct HintStatistics {
+    hints: HashMap<String, HintStats>,
+}
+
+impl HintStatistics {
+    pub fn record(&mut self, code: &str, elapsed: Duration) {
+        let stats = match self.hints.get_mut(code) {
+            Some(stats) => stats,
+            None => self.hints.entry(code.to_string()).or_default(),
+        };
+        stats.executions += 1;
+        stats.total_time += elapsed;
+    }
+
+    pub fn get(&self, code: &str) -> Option<&HintStats> {
+        self.hints.get(code)
+    }
+
+    /// The hints that ran, the slowest in total first, and those of `registered` that never ran.
+    pub fn report<'a>(&self, registered: impl IntoIterator<Item = &'a str>) -> HintStatisticsReport {
+        let mut hints = self
+            .hints
+            .iter()
+            .map(|(code, stats)| HintReportEntry {
+                code: code.clone(),
+                executions: stats.executions,
+                total_time_ns: stats.total_time.as_nanos().try_into().unwrap_or(u64::MAX),
+            })
+            .collect::<Vec<_>>();
+        hints.sort_by(|a, b| b.total_time_ns.cmp(&a.total_time_ns).then_with(|| a.code.cmp(&b.code)));
+
+        // The program may format a hint differently from its registration, see `canonical_hint_code`.
+        let executed = self.hints.keys().map(|code| canonical_hint_code(code)).collect::<HashSet<_>>();
+        let mut unused = registered
+            .into_iter()
+            .filter(|code| !executed.contains(&canonical_hint_code(code)))
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        unused.sort();
+
+        HintStatisticsReport { hints, unused }
+    }
+}
+
+/// Hint statistics as written by the runner.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HintStatisticsReport {
+    pub hints: Vec<HintReportEntry>,
+    /// Registered hints that never ran.
+    pub unused: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HintReportEntry {
+    pub code: String,
+    pub executions: u64,
+    pub total_time_ns: u64,
+}
@@ -0,0 +1,67 @@
+use cairo_vm::serde::deserialize_program::{Location, ProgramJson};
+use std::fmt;
+
+/// A hint of a program that no hint processor implements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownHint {
+    pub code: String,
+    /// Where the hint is in the Cairo sources, as `file:line:column`, or its pc without debug info.
+    pub locations: Vec<String>,
+}
+
+/// The hints of a program that no hint processor implements, each listed once with all of its locations.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UnknownHintReport {
+    pub hints: Vec<UnknownHint>,
+}
+
+impl UnknownHintReport {
+    /// Lists the hints of `program` for which `is_known` is false, in the order of their first pc.
+    pub fn new(program: &ProgramJson, mut is_known: impl FnMut(&str) -> bool) -> Self {
+        let instruction_locations = program.debug_info.as_ref().map(|debug_info| debug_info.get_instruction_locations());
+
+        let mut hints: Vec<UnknownHint> = Vec::new();
+        for (pc, hints_at_pc) in &program.hints {
+            for (index, hint) in hints_at_pc.iter().enumerate() {
+                if is_known(&hint.code) {
+                    continue;
+                }
+                let location = instruction_locations
+                    .as_ref()
+                    .and_then(|locations| locations.get(pc))
+                    .and_then(|location| location.hints.get(index))
+                    .map_or_else(|| format!("pc {pc}"), |hint_location| format_location(&hint_location.location));
+                match hints.iter_mut().find(|unknown| unknown.code == hint.code) {
+                    Some(unknown) => unknown.locations.push(location),
+                    None => hints.push(UnknownHint {
+                        code: hint.code.clone(),
+                        locations: vec![location],
+                    }),
+                }
+            }
+        }
+        Self { hints }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hints.is_empty()
+    }
+}
+
+impl fmt::Display for UnknownHintReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for hint in &self.hints {
+            for location in &hint.locations {
+                writeln!(f, "{location}:")?;
+            }
+            for line in hint.code.lines() {
+                writeln!(f, "    {line}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn format_location(location: &Location) -> String {
+    format!("{}:{}:{}", location.input_file.filename, location.start_line, location.start_col)
+}
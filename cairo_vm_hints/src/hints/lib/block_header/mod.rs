@@ -7,6 +7,10 @@ use cairo_vm::Felt252;
 use std::cmp::Ordering;
 use std::collections::HashMap;
 
+use crate::hint_processor::ProgramInput;
+use crate::hints::HintFunction;
+use crate::utils::write_vector;
+
 const HINT_RLP_BIGINT_SIZE: &str = "memory[ap] = 1 if ids.byte <= 127 else 0";
 
 const FELT_127: Felt252 = Felt252::from_hex_unchecked("0x7F");
@@ -25,14 +29,34 @@ fn hint_rlp_bigint_size(
     Ok(())
 }
 
-pub fn run_hint(
+const HINT_READ_BLOCK_HEADERS: &str = "block_headers_array = program_input['block_headers_array']\nbytes_len_array = program_input['bytes_len_array']\nsegments.write_arg(ids.block_headers_array, block_headers_array)\nsegments.write_arg(ids.block_headers_array_bytes_len, bytes_len_array)";
+
+fn hint_read_block_headers(
     vm: &mut VirtualMachine,
     exec_scope: &mut ExecutionScopes,
     hint_data: &HintProcessorData,
-    constants: &HashMap<String, Felt252>,
+    _constants: &HashMap<String, Felt252>,
 ) -> Result<(), HintError> {
-    match hint_data.code.as_str() {
-        HINT_RLP_BIGINT_SIZE => hint_rlp_bigint_size(vm, exec_scope, hint_data, constants),
-        _ => Err(HintError::UnknownHint(hint_data.code.to_string().into_boxed_str())),
+    let program_input = ProgramInput::from_scopes(exec_scope)?;
+    let block_headers_array = program_input.nested_felts("block_headers_array")?;
+    let bytes_len_array = program_input
+        .felts("bytes_len_array")?
+        .into_iter()
+        .map(MaybeRelocatable::Int)
+        .collect::<Vec<_>>();
+
+    let mut block_headers = vec![];
+    for block_header in block_headers_array {
+        let segment = vm.add_memory_segment();
+        vm.segments
+            .load_data(segment, &block_header.into_iter().map(MaybeRelocatable::Int).collect::<Vec<_>>())?;
+        block_headers.push(MaybeRelocatable::from(segment));
     }
+    write_vector("block_headers_array", &block_headers, vm, hint_data)?;
+    write_vector("block_headers_array_bytes_len", &bytes_len_array, vm, hint_data)
 }
+
+pub const HINTS: &[(&str, HintFunction)] = &[
+    (HINT_RLP_BIGINT_SIZE, hint_rlp_bigint_size),
+    (HINT_READ_BLOCK_HEADERS, hint_read_block_headers),
+];
@@ -42,6 +42,8 @@ use cairo_vm::vm::{errors::hint_errors::HintError, vm_core::VirtualMachine};
 use cairo_vm::Felt252;
 use std::collections::HashMap;
 
+use crate::hints::HintFunction;
+
 pub const HINT_LONG_SHORT_LIST: &str = "from tools.py.hints import is_short_list, is_long_list\nif is_short_list(ids.list_prefix):\n    ids.long_short_list = 0\nelif is_long_list(ids.list_prefix):\n    ids.long_short_list = 1\nelse:\n    raise ValueError(f\"Invalid list prefix: {hex(ids.list_prefix)}. Not a recognized list type.\")";
 
 pub fn hint_long_short_list(
@@ -177,17 +179,9 @@ pub fn hint_item_type(
     }
 }
 
-pub fn run_hint(
-    vm: &mut VirtualMachine,
-    exec_scope: &mut ExecutionScopes,
-    hint_data: &HintProcessorData,
-    constants: &HashMap<String, Felt252>,
-) -> Result<(), HintError> {
-    match hint_data.code.as_str() {
-        HINT_LONG_SHORT_LIST => hint_long_short_list(vm, exec_scope, hint_data, constants),
-        HINT_FIRST_ITEM_TYPE => hint_first_item_type(vm, exec_scope, hint_data, constants),
-        HINT_SECOND_ITEM_TYPE => hint_second_item_type(vm, exec_scope, hint_data, constants),
-        HINT_ITEM_TYPE => hint_item_type(vm, exec_scope, hint_data, constants),
-        _ => Err(HintError::UnknownHint(hint_data.code.to_string().into_boxed_str())),
-    }
-}
+pub const HINTS: &[(&str, HintFunction)] = &[
+    (HINT_LONG_SHORT_LIST, hint_long_short_list),
+    (HINT_FIRST_ITEM_TYPE, hint_first_item_type),
+    (HINT_SECOND_ITEM_TYPE, hint_second_item_type),
+    (HINT_ITEM_TYPE, hint_item_type),
+];
@@ -1,3 +1,4 @@
+use crate::hints::HintValueError;
 use crate::utils;
 use cairo_vm::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::HintProcessorData;
 use cairo_vm::hint_processor::builtin_hint_processor::hint_utils::get_relocatable_from_var_name;
@@ -17,14 +18,10 @@ pub fn hint_expected_leading_zeroes(
 ) -> Result<(), HintError> {
     let x_ptr = get_relocatable_from_var_name("x", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
 
-    let x = vm
-        .get_continuous_range(x_ptr, 2)?
-        .into_iter()
-        .map(|v| v.get_int().unwrap())
-        .collect::<Vec<Felt252>>();
+    let x = vm.get_integer_range(x_ptr, 2)?;
 
-    let x_low: u128 = x[0].try_into().unwrap();
-    let x_high: u128 = x[1].try_into().unwrap();
+    let x_low: u128 = utils::felt_to("hint_expected_leading_zeroes", "x.low", *x[0])?;
+    let x_high: u128 = utils::felt_to("hint_expected_leading_zeroes", "x.high", *x[1])?;
 
     let cut_nibble = utils::get_value("cut_nibble", vm, hint_data)?;
 
@@ -55,27 +52,27 @@ pub fn hint_expected_nibble(
 ) -> Result<(), HintError> {
     let key_ptr = get_relocatable_from_var_name("key", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
 
-    let key = vm
-        .get_continuous_range(key_ptr, 2)?
-        .into_iter()
-        .map(|v| v.get_int().unwrap())
-        .collect::<Vec<Felt252>>();
+    let key = vm.get_integer_range(key_ptr, 2)?;
 
-    let key_low: u128 = key[0].try_into().unwrap();
-    let key_high: u128 = key[1].try_into().unwrap();
+    let key_low: u128 = utils::felt_to("hint_expected_nibble", "key.low", *key[0])?;
+    let key_high: u128 = utils::felt_to("hint_expected_nibble", "key.high", *key[1])?;
 
-    let key_leading_zeroes_nibbles: usize = utils::get_value("key_leading_zeroes_nibbles", vm, hint_data)?.try_into().unwrap();
-    let nibble_index: usize = utils::get_value("nibble_index", vm, hint_data)?.try_into().unwrap();
+    let nibble_index: usize = utils::get_value_as("hint_expected_nibble", "nibble_index", vm, hint_data)?;
 
-    let hex = hex::encode([key_high.to_be_bytes(), key_low.to_be_bytes()].concat())
-        .trim_start_matches('0')
-        .to_string();
-    let nibble_char = format!("{:0width$}{}", "", hex, width = key_leading_zeroes_nibbles)
-        .chars()
-        .nth(nibble_index + key_leading_zeroes_nibbles)
-        .unwrap();
+    // `key_hex[ids.nibble_index + ids.key_leading_zeroes_nibbles]` skips the zeroes prepended to the key.
+    let hex = hex::encode([key_high.to_be_bytes(), key_low.to_be_bytes()].concat());
+    let hex = hex.trim_start_matches('0').as_bytes();
+    let nibble = hex
+        .get(nibble_index)
+        .and_then(|nibble_char| (*nibble_char as char).to_digit(16))
+        .ok_or(HintValueError::OutOfBounds {
+            hint: "hint_expected_nibble",
+            variable: "nibble_index",
+            value: nibble_index,
+            limit: hex.len(),
+        })?;
 
-    exec_scope.insert_value("expected_nibble", nibble_char.to_digit(16).unwrap());
+    exec_scope.insert_value("expected_nibble", nibble);
 
     Ok(())
 }
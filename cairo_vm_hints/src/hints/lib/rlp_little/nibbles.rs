@@ -19,13 +19,15 @@ pub fn hint_is_zero(
     hint_data: &HintProcessorData,
     _constants: &HashMap<String, Felt252>,
 ) -> Result<(), HintError> {
-    let nibble_index: i128 = utils::get_value("nibble_index", vm, hint_data)?.try_into().unwrap();
-    let key_leading_zeroes_nibbles: i128 = utils::get_value("key_leading_zeroes_nibbles", vm, hint_data)?.try_into().unwrap();
+    let nibble_index: Felt252 = utils::get_value("nibble_index", vm, hint_data)?;
+    let key_leading_zeroes_nibbles: Felt252 = utils::get_value("key_leading_zeroes_nibbles", vm, hint_data)?;
+
+    // `nibble_index <= key_leading_zeroes_nibbles - 1` on the integer values of the felts, which cannot wrap around.
     utils::write_value(
         "is_zero",
-        match nibble_index.cmp(&(key_leading_zeroes_nibbles - 1)) {
-            Ordering::Less | Ordering::Equal => Felt252::ONE,
-            Ordering::Greater => Felt252::ZERO,
+        match nibble_index.cmp(&key_leading_zeroes_nibbles) {
+            Ordering::Less => Felt252::ONE,
+            Ordering::Equal | Ordering::Greater => Felt252::ZERO,
         },
         vm,
         hint_data,
@@ -7,7 +7,7 @@ use cairo_vm::Felt252;
 use starknet_types_core::felt::NonZeroFelt;
 use std::collections::HashMap;
 
-const FELT_8: Felt252 = Felt252::from_hex_unchecked("0x08");
+const FELT_8: NonZeroFelt = NonZeroFelt::from_felt_unchecked(Felt252::from_hex_unchecked("0x08"));
 
 pub const HINT_VALUE_DIV32: &str = "ids.q, ids.r = divmod(ids.value, ids.DIV_32)";
 
@@ -20,7 +20,7 @@ pub fn hint_value_div32(
     let value: Felt252 = utils::get_value("value", vm, hint_data)?;
     let div_32: Felt252 = *get_constant_from_var_name("DIV_32", constants)?;
 
-    let (q, r) = value.div_rem(&NonZeroFelt::try_from(div_32).unwrap());
+    let (q, r) = value.div_rem(&utils::non_zero("hint_value_div32", "DIV_32", div_32)?);
     utils::write_value("q", q, vm, hint_data)?;
     utils::write_value("r", r, vm, hint_data)
 }
@@ -35,7 +35,7 @@ pub fn hint_value_8(
 ) -> Result<(), HintError> {
     let value: Felt252 = utils::get_value("value", vm, hint_data)?;
 
-    let (q, r) = value.div_rem(&NonZeroFelt::try_from(FELT_8).unwrap());
+    let (q, r) = value.div_rem(&FELT_8);
     utils::write_value("q", q, vm, hint_data)?;
     utils::write_value("r", r, vm, hint_data)
 }
@@ -51,7 +51,7 @@ pub fn hint_value_div(
     let value: Felt252 = utils::get_value("value", vm, hint_data)?;
     let div: Felt252 = utils::get_value("div", vm, hint_data)?;
 
-    let (q, r) = value.div_rem(&NonZeroFelt::try_from(div).unwrap());
+    let (q, r) = value.div_rem(&utils::non_zero("hint_value_div", "div", div)?);
     utils::write_value("q", q, vm, hint_data)?;
     utils::write_value("r", r, vm, hint_data)
 }
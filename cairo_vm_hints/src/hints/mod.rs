@@ -1,27 +1,75 @@
 use cairo_vm::{
     hint_processor::builtin_hint_processor::builtin_hint_processor_definition::HintProcessorData,
     types::exec_scope::ExecutionScopes,
-    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    vm::{
+        errors::{hint_errors::HintError, vm_errors::VirtualMachineError},
+        vm_core::VirtualMachine,
+    },
     Felt252,
 };
 use std::collections::HashMap;
+use thiserror::Error;
+
+use crate::hint_processor::HintModule;
 
 pub mod lib;
 pub mod tests;
 
-pub fn run_hint(
-    vm: &mut VirtualMachine,
-    exec_scope: &mut ExecutionScopes,
-    hint_data: &HintProcessorData,
-    constants: &HashMap<String, Felt252>,
-) -> Result<(), HintError> {
-    let hints = [lib::run_hint, tests::run_hint];
-
-    for hint in hints.iter() {
-        let res = hint(vm, exec_scope, hint_data, constants);
-        if !matches!(res, Err(HintError::UnknownHint(_))) {
-            return res;
+/// Signature shared by every hint implementation.
+pub type HintFunction = fn(&mut VirtualMachine, &mut ExecutionScopes, &HintProcessorData, &HashMap<String, Felt252>) -> Result<(), HintError>;
+
+/// A hint given a value it cannot handle, naming the hint and the variable holding the value.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum HintValueError {
+    #[error("{hint}: {variable} = {value} does not fit in {ty}")]
+    OutOfRange {
+        hint: &'static str,
+        variable: &'static str,
+        value: Felt252,
+        ty: &'static str,
+    },
+    #[error("{hint}: {variable} must not be zero")]
+    Zero { hint: &'static str, variable: &'static str },
+    #[error("{hint}: {variable} = {value} is out of bounds, the limit is {limit}")]
+    OutOfBounds {
+        hint: &'static str,
+        variable: &'static str,
+        value: usize,
+        limit: usize,
+    },
+}
+
+impl HintValueError {
+    /// The `HintValueError` that failed a hint, if any.
+    pub fn from_hint_error(error: &HintError) -> Option<&Self> {
+        match error {
+            HintError::Internal(VirtualMachineError::Other(error)) => error.downcast_ref(),
+            _ => None,
         }
     }
-    Err(HintError::UnknownHint(hint_data.code.to_string().into_boxed_str()))
+}
+
+/// Kept whole in the error chain, see `HintValueError::from_hint_error`.
+impl From<HintValueError> for HintError {
+    fn from(error: HintValueError) -> Self {
+        HintError::Internal(VirtualMachineError::Other(error.into()))
+    }
+}
+
+/// Every hint of the crate along with its code.
+pub fn hints() -> impl Iterator<Item = (&'static str, HintFunction)> {
+    lib::hints().chain(tests::HINTS.iter().copied())
+}
+
+/// The hints of the crate, registered in every `HintRegistry::new`.
+pub struct EthEssentialsHints;
+
+impl HintModule for EthEssentialsHints {
+    fn name(&self) -> &str {
+        "eth_essentials"
+    }
+
+    fn hints(&self) -> Vec<(&'static str, HintFunction)> {
+        hints().collect()
+    }
 }
@@ -1,4 +1,4 @@
-use crate::mmr::{Keccak, Mmr, Poseidon};
+use crate::mmr::{Digest, DualMmr};
 use crate::utils::{split_u256, write_struct, write_value, write_vector};
 use cairo_vm::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::HintProcessorData;
 use cairo_vm::types::exec_scope::ExecutionScopes;
@@ -115,36 +115,33 @@ pub fn test_construct_mmr(
         hint_data,
     )?;
 
-    let mut mmr_poseidon = Mmr::<Poseidon>::new();
-    let mut mmr_keccak = Mmr::<Keccak>::new();
-
-    (0..previous_n_values)
-        .map(|_| rng.gen_biguint_range(&BigUint::one(), &stark_prime))
-        .for_each(|x| mmr_poseidon.append(x));
-
-    (0..previous_n_values)
-        .map(|_| rng.gen_biguint_range(&BigUint::one(), &two_pow_256))
-        .for_each(|x| mmr_keccak.append(x));
-
-    write_value("mmr_offset", mmr_poseidon.size(), vm, hint_data)?;
+    let mut mmr = DualMmr::new();
+    for _ in 0..previous_n_values {
+        mmr.append(
+            Felt252::try_from_biguint(&rng.gen_biguint_range(&BigUint::one(), &stark_prime))?,
+            <[u8; 32]>::try_from_biguint(&rng.gen_biguint_range(&BigUint::one(), &two_pow_256))?,
+        )?;
+    }
 
-    let previous_peaks_poseidon = mmr_poseidon.retrieve_nodes(mmr_poseidon.get_peaks());
-    let previous_peaks_keccak = mmr_keccak.retrieve_nodes(mmr_keccak.get_peaks());
+    let previous_state = mmr.get_state()?;
+    write_value("mmr_offset", previous_state.size, vm, hint_data)?;
 
     write_vector(
         "previous_peaks_values_poseidon",
-        &previous_peaks_poseidon
+        &previous_state
+            .peaks_poseidon
             .iter()
-            .map(|x| MaybeRelocatable::Int(x.into()))
+            .map(|x| MaybeRelocatable::Int(*x))
             .collect::<Vec<_>>(),
         vm,
         hint_data,
     )?;
     write_vector(
         "previous_peaks_values_keccak",
-        &previous_peaks_keccak
+        &previous_state
+            .peaks_keccak
             .iter()
-            .flat_map(split_u256)
+            .flat_map(|x| split_u256(&x.to_biguint()))
             .map(|x| MaybeRelocatable::Int(x.into()))
             .collect::<Vec<_>>(),
         vm,
@@ -152,13 +149,13 @@ pub fn test_construct_mmr(
     )?;
     write_value(
         "mmr_last_root_poseidon",
-        MaybeRelocatable::Int(mmr_poseidon.get_root().into()),
+        MaybeRelocatable::Int(previous_state.root_poseidon),
         vm,
         hint_data,
     )?;
     write_struct(
         "mmr_last_root_keccak",
-        &split_u256(&mmr_keccak.get_root())
+        &split_u256(&previous_state.root_keccak.to_biguint())
             .iter()
             .map(|x| MaybeRelocatable::Int(x.into()))
             .collect::<Vec<_>>(),
@@ -166,29 +163,27 @@ pub fn test_construct_mmr(
         hint_data,
     )?;
 
-    for elem in poseidon_hash_array.iter().rev() {
-        mmr_poseidon.append(elem.clone());
-    }
-    for elem in keccak_hash_array.iter().rev() {
-        mmr_keccak.append(elem.clone());
+    for (poseidon_elem, keccak_elem) in poseidon_hash_array.iter().zip(keccak_hash_array.iter()).rev() {
+        mmr.append(Felt252::try_from_biguint(poseidon_elem)?, <[u8; 32]>::try_from_biguint(keccak_elem)?)?;
     }
 
+    let new_state = mmr.get_state()?;
     write_value(
         "expected_new_root_poseidon",
-        MaybeRelocatable::Int(mmr_poseidon.get_root().into()),
+        MaybeRelocatable::Int(new_state.root_poseidon),
         vm,
         hint_data,
     )?;
     write_struct(
         "expected_new_root_keccak",
-        &split_u256(&mmr_keccak.get_root())
+        &split_u256(&new_state.root_keccak.to_biguint())
             .iter()
             .map(|x| MaybeRelocatable::Int(x.into()))
             .collect::<Vec<_>>(),
         vm,
         hint_data,
     )?;
-    write_value("expected_new_len", mmr_keccak.size(), vm, hint_data)?;
+    write_value("expected_new_len", new_state.size, vm, hint_data)?;
 
     Ok(())
 }
@@ -4,24 +4,10 @@ use cairo_vm::types::relocatable::MaybeRelocatable;
 use cairo_vm::vm::{errors::hint_errors::HintError, vm_core::VirtualMachine};
 use cairo_vm::Felt252;
 use rand::{thread_rng, Rng};
-use starknet_types_core::felt::Felt;
 use std::collections::{HashMap, HashSet};
 
-use crate::utils::{get_value, write_vector};
-
-fn is_valid_mmr_size(mut mmr_size: u64) -> bool {
-    if mmr_size == 0 {
-        return false;
-    }
-    let max_height = Felt::from(mmr_size).bits() as u32;
-    for height in (0..=max_height).rev() {
-        let node_count = 2u64.pow(height + 1) - 1;
-        if node_count <= mmr_size {
-            mmr_size -= node_count;
-        }
-    }
-    mmr_size == 0
-}
+use crate::mmr::position::{is_valid_mmr_size, leaf_count_to_mmr_size};
+use crate::utils::{get_value_as, write_vector};
 
 pub const HINT_GENERATE_RANDOM: &str = "from tools.py.mmr import is_valid_mmr_size\nimport random\nprint(f\"Testing is_valid_mmr_size against python implementation with {ids.num_sizes} random sizes in [0, 20000000)...\")\nsizes_to_test = random.sample(range(0, 20000000), ids.num_sizes)\nexpected_output = [is_valid_mmr_size(size) for size in sizes_to_test]\nsegments.write_arg(ids.expected_output, expected_output)\nsegments.write_arg(ids.input_array, sizes_to_test)";
 
@@ -35,7 +21,7 @@ pub fn hint_generate_random(
     // let ap_tracking = &hint_data.ap_tracking;
     // let a = get_integer_from_var_name("x", vm, ids_data, ap_tracking)?;
     // vm.segments.write_arg(vm.seg, arg)
-    let num_sizes: u64 = get_value("num_sizes", vm, hint_data)?.try_into().unwrap();
+    let num_sizes: u64 = get_value_as("hint_generate_random", "num_sizes", vm, hint_data)?;
 
     println!(
         "Testing is_valid_mmr_size against python implementation with {} random sizes in [0, 20000000)...",
@@ -70,14 +56,14 @@ pub fn hint_generate_sequential(
     // let ap_tracking = &hint_data.ap_tracking;
     // let a = get_integer_from_var_name("x", vm, ids_data, ap_tracking)?;
     // vm.segments.write_arg(vm.seg, arg)
-    let num_elems: u64 = get_value("num_elems", vm, hint_data)?.try_into().unwrap();
+    let num_elems: usize = get_value_as("hint_generate_sequential", "num_elems", vm, hint_data)?;
 
     println!("Testing is_valid_mmr_size by creating the mmr for all sizes in [0, {})...", num_elems);
 
     let mut valid_mmr_sizes = HashSet::new();
     let mut mmr_size = 0;
     for leaf_count in 1..=num_elems {
-        mmr_size = 2 * leaf_count - (leaf_count.count_ones() as u64);
+        mmr_size = leaf_count_to_mmr_size(leaf_count);
         valid_mmr_sizes.insert(mmr_size);
     }
     let expected_output = (0..=mmr_size)
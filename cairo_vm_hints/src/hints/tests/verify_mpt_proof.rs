@@ -0,0 +1,80 @@
+use cairo_vm::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::HintProcessorData;
+use cairo_vm::types::exec_scope::ExecutionScopes;
+use cairo_vm::types::relocatable::MaybeRelocatable;
+use cairo_vm::vm::{errors::hint_errors::HintError, vm_core::VirtualMachine};
+use cairo_vm::Felt252;
+use std::collections::HashMap;
+
+use crate::hint_processor::ProgramInput;
+use crate::mpt::{bytes_to_le_chunks, Uint256};
+use crate::utils::{write_struct, write_value, write_vector};
+
+pub const HINT_READ_MPT_PROOF: &str = "from tools.py.utils import bytes_to_8_bytes_chunks_little, split_128, reverse_endian_256, count_leading_zero_nibbles_from_hex\n\nproof = [bytes.fromhex(node) for node in program_input[\"proof\"]]\nsegments.write_arg(ids.proof, [bytes_to_8_bytes_chunks_little(node) for node in proof])\nsegments.write_arg(ids.proof_bytes_len, [len(node) for node in proof])\nids.proof_len = len(proof)\n\n(ids.root.low, ids.root.high) = split_128(reverse_endian_256(int(program_input[\"root\"], 16)))\nids.key_leading_zeroes = count_leading_zero_nibbles_from_hex(program_input[\"key\"])\n(ids.key.low, ids.key.high) = split_128(int(program_input[\"key\"], 16))";
+
+pub fn hint_read_mpt_proof(
+    vm: &mut VirtualMachine,
+    exec_scope: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let program_input = ProgramInput::from_scopes(exec_scope)?;
+    let proof = program_input
+        .get("proof")?
+        .as_array()
+        .and_then(|nodes| nodes.iter().map(|node| hex::decode(node.as_str()?).ok()).collect::<Option<Vec<_>>>())
+        .ok_or_else(|| HintError::CustomHint("Program input proof is not an array of hex nodes".into()))?;
+
+    let mut nodes = vec![];
+    for node in &proof {
+        let segment = vm.add_memory_segment();
+        let chunks = bytes_to_le_chunks(node)
+            .into_iter()
+            .map(|chunk| MaybeRelocatable::Int(chunk.into()))
+            .collect::<Vec<_>>();
+        vm.segments.load_data(segment, &chunks)?;
+        nodes.push(MaybeRelocatable::from(segment));
+    }
+    let bytes_len = proof.iter().map(|node| MaybeRelocatable::Int(node.len().into())).collect::<Vec<_>>();
+    write_vector("proof", &nodes, vm, hint_data)?;
+    write_vector("proof_bytes_len", &bytes_len, vm, hint_data)?;
+    write_value("proof_len", MaybeRelocatable::Int(proof.len().into()), vm, hint_data)?;
+
+    let root = Uint256::from_le_bytes(hex_word(&program_input, "root")?);
+    write_struct(
+        "root",
+        &[MaybeRelocatable::Int(root.low.into()), MaybeRelocatable::Int(root.high.into())],
+        vm,
+        hint_data,
+    )?;
+
+    let key_hex = hex_string(&program_input, "key")?;
+    let key_leading_zeroes = key_hex.chars().take_while(|char| *char == '0').count();
+    write_value("key_leading_zeroes", MaybeRelocatable::Int(key_leading_zeroes.into()), vm, hint_data)?;
+
+    let key = Uint256::from_be_bytes(hex_word(&program_input, "key")?);
+    write_struct(
+        "key",
+        &[MaybeRelocatable::Int(key.low.into()), MaybeRelocatable::Int(key.high.into())],
+        vm,
+        hint_data,
+    )
+}
+
+/// The digits of a hex string of the program input, without the `0x` prefix.
+fn hex_string<'a>(program_input: &'a ProgramInput, key: &str) -> Result<&'a str, HintError> {
+    let string = program_input
+        .get(key)?
+        .as_str()
+        .ok_or_else(|| HintError::CustomHint(format!("Program input {key} is not a hex string").into_boxed_str()))?;
+    Ok(string.strip_prefix("0x").unwrap_or(string))
+}
+
+/// A hex string of the program input as a big-endian 32-byte word, as read by `int(.., 16)`.
+fn hex_word(program_input: &ProgramInput, key: &str) -> Result<[u8; 32], HintError> {
+    let digits = hex_string(program_input, key)?;
+    let padded = format!("{digits:0>64}");
+    let mut word = [0u8; 32];
+    hex::decode_to_slice(&padded, &mut word)
+        .map_err(|_| HintError::CustomHint(format!("Program input {key} is not a 256-bit hex number").into_boxed_str()))?;
+    Ok(word)
+}
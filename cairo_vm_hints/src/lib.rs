@@ -1,6 +1,10 @@
 pub mod hint_processor;
 pub mod hints;
 pub mod mmr;
+pub mod mpt;
 pub mod utils;
 
+#[cfg(test)]
+mod tests;
+
 pub use hint_processor::{CustomHintProcessor, ExtendedHintProcessor};
@@ -3,30 +3,33 @@
 pub mod hint_processor;
 pub mod hints;
 pub mod mmr;
+pub mod mpt;
 pub mod utils;
 
 use bincode::enc::write::Writer;
 use cairo_vm::air_public_input::PublicInputError;
 use cairo_vm::cairo_run::{self, EncodeTraceError};
-use cairo_vm::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor;
+use cairo_vm::serde::deserialize_program::deserialize_program_json;
 // TODO
 // #[cfg(feature = "with_tracer")]
 // use cairo_vm::serde::deserialize_program::DebugInfo;
+use cairo_vm::types::errors::program_errors::ProgramError;
 use cairo_vm::types::layout::CairoLayoutParams;
 use cairo_vm::types::layout_name::LayoutName;
 use cairo_vm::vm::errors::cairo_run_errors::CairoRunError;
 use cairo_vm::vm::errors::trace_errors::TraceError;
 use cairo_vm::vm::errors::vm_errors::VirtualMachineError;
+use cairo_vm::vm::errors::vm_exception::VmException;
 use cairo_vm::vm::runners::cairo_pie::CairoPie;
 // #[cfg(feature = "with_tracer")]
 // use cairo_vm::vm::runners::cairo_runner::CairoRunner;
 use cairo_vm::vm::runners::cairo_runner::RunResources;
-use hint_processor::ExtendedHintProcessor;
+use hint_processor::{ExtendedHintProcessor, ProgramInput, UnknownHintReport};
 // #[cfg(feature = "with_tracer")]
 // use cairo_vm_tracer::error::trace_data_errors::TraceDataError;
 // #[cfg(feature = "with_tracer")]
 // use cairo_vm_tracer::tracer::run_tracer;
-use clap::{Parser, ValueHint};
+use clap::{Parser, ValueEnum, ValueHint};
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 use thiserror::Error;
@@ -60,6 +63,18 @@ struct Args {
     cairo_layout_params_file: Option<PathBuf>,
     #[structopt(long = "proof_mode")]
     proof_mode: bool,
+    /// JSON file exposed to the hints as `program_input`.
+    #[clap(long = "program_input", value_hint=ValueHint::FilePath)]
+    program_input: Option<PathBuf>,
+    /// What to do when the program has hints that are not implemented, all of them being listed before the run.
+    #[clap(long = "unknown_hints", default_value = "permissive", value_enum)]
+    unknown_hints: UnknownHintPolicy,
+    /// Where to write the execution counts and times of the hints, and the hints that never ran, as JSON.
+    #[clap(long = "hint_statistics", value_hint=ValueHint::FilePath)]
+    hint_statistics: Option<PathBuf>,
+    /// Stop the run after this many steps.
+    #[clap(long = "max_steps")]
+    max_steps: Option<usize>,
     #[structopt(long = "secure_run")]
     secure_run: Option<bool>,
     #[clap(long = "air_public_input", requires = "proof_mode")]
@@ -81,15 +96,25 @@ struct Args {
     #[structopt(long = "tracer")]
     // #[cfg(feature = "with_tracer")]
     // tracer: bool,
+    /// The program of a PIE is stripped of its hints, so the run has none and takes exactly the steps of the PIE.
     #[structopt(
         long = "run_from_cairo_pie",
         // We need to add these air_private_input & air_public_input or else
-        // passing run_from_cairo_pie + either of these without proof_mode will not fail
-        conflicts_with_all = ["proof_mode", "air_private_input", "air_public_input"]
+        // passing run_from_cairo_pie + either of these without proof_mode will not fail.
+        // The hint arguments would have no effect.
+        conflicts_with_all = ["proof_mode", "air_private_input", "air_public_input", "program_input", "hint_statistics", "max_steps", "unknown_hints"]
     )]
     run_from_cairo_pie: bool,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum UnknownHintPolicy {
+    /// Fail before running the program.
+    Strict,
+    /// Run the program, which fails only if it reaches one of them.
+    Permissive,
+}
+
 #[derive(Debug, Error)]
 enum Error {
     #[error("Invalid arguments")]
@@ -106,6 +131,16 @@ enum Error {
     Trace(#[from] TraceError),
     #[error(transparent)]
     PublicInput(#[from] PublicInputError),
+    #[error("Invalid program input")]
+    ProgramInput(#[from] serde_json::Error),
+    #[error(transparent)]
+    Program(#[from] ProgramError),
+    #[error("The program has hints that are not implemented:\n{0}")]
+    UnknownHints(UnknownHintReport),
+    #[error("The run exceeded the limit of {0} steps")]
+    StepLimit(usize),
+    #[error("Failed to serialize the hint statistics")]
+    HintStatistics(serde_json::Error),
     // #[error(transparent)]
     // #[cfg(feature = "with_tracer")]
     // TraceData(#[from] TraceDataError),
@@ -168,6 +203,24 @@ impl FileWriter {
 //     Ok(())
 // }
 
+/// Reports the hints of the program that no hint processor implements, before running it.
+fn check_unknown_hints(unknown_hints: UnknownHintReport, policy: UnknownHintPolicy) -> Result<(), Error> {
+    if !unknown_hints.is_empty() {
+        match policy {
+            UnknownHintPolicy::Strict => return Err(Error::UnknownHints(unknown_hints)),
+            UnknownHintPolicy::Permissive => eprintln!("Warning: the program has hints that are not implemented:\n{unknown_hints}"),
+        }
+    }
+    Ok(())
+}
+
+fn write_hint_statistics(file_path: Option<&Path>, hint_processor: &ExtendedHintProcessor) -> Result<(), Error> {
+    if let (Some(file_path), Some(report)) = (file_path, hint_processor.statistics_report()) {
+        std::fs::write(file_path, serde_json::to_string_pretty(&report).map_err(Error::HintStatistics)?)?;
+    }
+    Ok(())
+}
+
 fn run(args: impl Iterator<Item = String>) -> Result<(), Error> {
     let args = Args::try_parse_from(args)?;
 
@@ -190,22 +243,61 @@ fn run(args: impl Iterator<Item = String>) -> Result<(), Error> {
         ..Default::default()
     };
 
+    let mut hint_processor = ExtendedHintProcessor::new();
+    if let Some(ref file) = args.program_input {
+        hint_processor.set_program_input(ProgramInput::new(serde_json::from_slice(&std::fs::read(file)?)?));
+    }
+    if args.hint_statistics.is_some() {
+        hint_processor.enable_statistics();
+    }
+    if let Some(max_steps) = args.max_steps {
+        hint_processor.set_max_steps(max_steps);
+    }
+
     let mut cairo_runner = match if args.run_from_cairo_pie {
         let pie = CairoPie::read_zip_file(&args.filename)?;
-        let mut hint_processor = BuiltinHintProcessor::new(Default::default(), RunResources::new(pie.execution_resources.n_steps));
+        // `cairo_run_pie` requires run resources of exactly the steps of the PIE.
+        hint_processor.set_run_resources(RunResources::new(pie.execution_resources.n_steps));
         cairo_run::cairo_run_pie(&pie, &cairo_run_config, &mut hint_processor)
     } else {
         let program_content = std::fs::read(args.filename).map_err(Error::IO)?;
-        let mut hint_processor = ExtendedHintProcessor::new();
+        check_unknown_hints(
+            hint_processor.unknown_hints(&deserialize_program_json(&program_content)?),
+            args.unknown_hints,
+        )?;
         cairo_run::cairo_run(&program_content, &cairo_run_config, &mut hint_processor)
     } {
         Ok(runner) => runner,
         Err(error) => {
             eprintln!("{error}");
-            return Err(Error::Runner(Box::new(error)));
+            // The statistics of a failed run show which hint it spent its time in. Failing to write them
+            // must not hide the error of the run.
+            if let Err(statistics_error) = write_hint_statistics(args.hint_statistics.as_deref(), &hint_processor) {
+                eprintln!("{statistics_error}");
+            }
+            return Err(match args.max_steps {
+                // `run_until_pc` fails with `UnfinishedExecution` when it stops before the end of the program,
+                // which `cairo_run` wraps in a `VmException`.
+                Some(max_steps)
+                    if hint_processor.steps_exhausted()
+                        && matches!(
+                            error,
+                            CairoRunError::VirtualMachine(VirtualMachineError::UnfinishedExecution)
+                                | CairoRunError::VmException(VmException {
+                                    inner_exc: VirtualMachineError::UnfinishedExecution,
+                                    ..
+                                })
+                        ) =>
+                {
+                    Error::StepLimit(max_steps)
+                }
+                _ => Error::Runner(Box::new(error)),
+            });
         }
     };
 
+    write_hint_statistics(args.hint_statistics.as_deref(), &hint_processor)?;
+
     if args.print_output {
         let mut output_buffer = "Program Output:\n".to_string();
         cairo_runner.vm.write_output(&mut output_buffer)?;
@@ -280,3 +372,87 @@ fn main() -> Result<(), Error> {
         other => other,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::{error::ErrorKind, CommandFactory};
+
+    fn parse(args: &[&str]) -> Result<Args, clap::Error> {
+        Args::try_parse_from(["cairo_vm_hints"].iter().chain(args))
+    }
+
+    #[test]
+    fn test_args() {
+        Args::command().debug_assert();
+    }
+
+    #[test]
+    fn test_cairo_pie_args() {
+        assert!(parse(&["program.zip", "--run_from_cairo_pie"]).unwrap().run_from_cairo_pie);
+        for arg in [
+            "--program_input=input.json",
+            "--hint_statistics=statistics.json",
+            "--max_steps=100",
+            "--unknown_hints=permissive",
+        ] {
+            let error = parse(&["program.zip", "--run_from_cairo_pie", arg]).unwrap_err();
+            assert_eq!(error.kind(), ErrorKind::ArgumentConflict, "{arg}");
+            // The error is reported by `run` before touching any file.
+            assert!(matches!(
+                run(["cairo_vm_hints", "program.zip", "--run_from_cairo_pie", arg]
+                    .map(str::to_string)
+                    .into_iter()),
+                Err(Error::Cli(_))
+            ));
+        }
+        assert!(parse(&["program.json", "--program_input=input.json", "--max_steps=100"]).is_ok());
+    }
+
+    /// Writes a program running the instructions `data` from `main` to a directory of its own, named after `name`.
+    fn write_program(name: &str, data: &[&str]) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("cairo_vm_hints_{name}"));
+        std::fs::create_dir_all(&directory).unwrap();
+        let program = directory.join("program.json");
+        let program_json = serde_json::json!({
+            "prime": "0x800000000000011000000000000000000000000000000000000000000000001",
+            "builtins": [],
+            "data": data,
+            "identifiers": { "__main__.main": { "type": "function", "pc": 0 } },
+            "hints": {},
+            "reference_manager": { "references": [] },
+        });
+        std::fs::write(&program, program_json.to_string()).unwrap();
+        program
+    }
+
+    fn run_program(program: &Path, args: &[&str]) -> Result<(), Error> {
+        let program = program.to_str().unwrap();
+        run(["cairo_vm_hints", program].iter().chain(args).map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_failed_run_with_unwritable_statistics() {
+        // A program whose first instruction does not decode.
+        let program = write_program("failed_run", &["0xffffffffffffffff"]);
+
+        // The statistics cannot be written to a directory, which leaves the error of the run as is.
+        let result = run_program(&program, &["--hint_statistics", program.parent().unwrap().to_str().unwrap()]);
+        assert!(matches!(result, Err(Error::Runner(_))), "{result:?}");
+    }
+
+    #[test]
+    fn test_step_limit() {
+        // `jmp rel 0`, which never returns.
+        let program = write_program("endless_run", &["0x10780017fff7fff", "0x0"]);
+        let result = run_program(&program, &["--max_steps=10"]);
+        assert!(matches!(result, Err(Error::StepLimit(10))), "{result:?}");
+        assert!(matches!(run_program(&program, &["--max_steps=1000"]), Err(Error::StepLimit(1000))));
+
+        // `ret`, which takes a single step.
+        let program = write_program("short_run", &["0x208b7fff7fff7ffe"]);
+        assert!(run_program(&program, &["--max_steps=1"]).is_ok());
+        assert!(run_program(&program, &["--max_steps=10"]).is_ok());
+        assert!(run_program(&program, &[]).is_ok());
+    }
+}
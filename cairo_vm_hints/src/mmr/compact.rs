@@ -0,0 +1,90 @@
+use super::position::mmr_size_to_leaf_count;
+use super::{compute_root, find_peaks, Hasher, Mmr, MmrError, Store};
+
+/// An MMR that only keeps its peaks, enough to keep appending and to compute the root.
+#[derive(Debug)]
+pub struct CompactMmr<H: Hasher> {
+    hasher: H,
+    peaks: Vec<H::Digest>,
+    size: usize,
+    leaf_count: usize,
+}
+
+impl<H: Hasher> Default for CompactMmr<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H: Hasher> CompactMmr<H> {
+    pub fn new() -> CompactMmr<H> {
+        CompactMmr {
+            hasher: H::new(),
+            peaks: vec![],
+            size: 0,
+            leaf_count: 0,
+        }
+    }
+
+    /// Resumes an MMR of `size` nodes from its peak values, ordered from left to right.
+    /// Returns `None` if `size` is not a valid MMR size or the number of peaks does not match it.
+    pub fn from_peaks(size: usize, peaks: Vec<H::Digest>) -> Option<CompactMmr<H>> {
+        if find_peaks(size)?.len() != peaks.len() {
+            return None;
+        }
+
+        Some(CompactMmr {
+            hasher: H::new(),
+            peaks,
+            size,
+            leaf_count: mmr_size_to_leaf_count(size)?,
+        })
+    }
+
+    /// Fails with `MmrError::EmptyMmr` until a leaf is appended.
+    pub fn get_root(&self) -> Result<H::Digest, MmrError> {
+        compute_root(&self.hasher, self.size, &self.peaks)
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn leaf_count(&self) -> usize {
+        self.leaf_count
+    }
+
+    pub fn get_peaks(&self) -> Vec<usize> {
+        find_peaks(self.size).expect("Invalid node count")
+    }
+
+    pub fn get_peak_values(&self) -> &[H::Digest] {
+        &self.peaks
+    }
+
+    pub fn append(&mut self, element: H::Digest) {
+        let no_merged_peaks = self.leaf_count.trailing_ones();
+        self.leaf_count += 1;
+        self.size += 1;
+        let mut last_node = element;
+        for _ in 0..no_merged_peaks {
+            let peak = self.peaks.pop().expect("Missing peak to merge with");
+            last_node = self.hasher.hash(&peak, &last_node);
+            self.size += 1;
+        }
+        self.peaks.push(last_node);
+    }
+}
+
+impl<H: Hasher, S: Store<H::Digest>> TryFrom<&Mmr<H, S>> for CompactMmr<H> {
+    type Error = MmrError;
+
+    fn try_from(mmr: &Mmr<H, S>) -> Result<Self, Self::Error> {
+        Ok(CompactMmr {
+            hasher: H::new(),
+            peaks: mmr.retrieve_nodes(mmr.get_peaks())?,
+            size: mmr.size(),
+            leaf_count: mmr.leaf_count,
+        })
+    }
+}
@@ -0,0 +1,84 @@
+use super::{Digest, Keccak, MemoryStore, Mmr, MmrError, Poseidon, Store};
+use crate::utils::split_u256;
+use num_bigint::BigUint;
+use starknet_types_core::felt::Felt;
+
+/// A Poseidon MMR and a Keccak MMR built side by side over the same blocks, as done by the chunk processor.
+#[derive(Debug)]
+pub struct DualMmr<P: Store<Felt> = MemoryStore<Felt>, K: Store<[u8; 32]> = MemoryStore<[u8; 32]>> {
+    poseidon: Mmr<Poseidon, P>,
+    keccak: Mmr<Keccak, K>,
+}
+
+/// Roots, peak values and shared size of a `DualMmr`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DualMmrState {
+    pub root_poseidon: Felt,
+    pub root_keccak: [u8; 32],
+    pub peaks_poseidon: Vec<Felt>,
+    pub peaks_keccak: Vec<[u8; 32]>,
+    pub size: usize,
+}
+
+impl DualMmrState {
+    /// Values laid out as in the chunk processor output: `root_poseidon, root_keccak.low, root_keccak.high, size`.
+    pub fn to_output(&self) -> Vec<BigUint> {
+        let [root_keccak_low, root_keccak_high] = split_u256(&self.root_keccak.to_biguint());
+        vec![self.root_poseidon.to_biguint(), root_keccak_low, root_keccak_high, self.size.into()]
+    }
+}
+
+impl Default for DualMmr {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DualMmr {
+    pub fn new() -> DualMmr {
+        DualMmr {
+            poseidon: Mmr::new(),
+            keccak: Mmr::new(),
+        }
+    }
+}
+
+impl<P: Store<Felt>, K: Store<[u8; 32]>> DualMmr<P, K> {
+    /// Resumes both MMRs from their stores, which must hold the same number of nodes.
+    pub fn with_stores(poseidon_store: P, keccak_store: K) -> Result<DualMmr<P, K>, MmrError> {
+        let poseidon = Mmr::with_store(poseidon_store)?;
+        let keccak = Mmr::with_store(keccak_store)?;
+        if poseidon.size() != keccak.size() {
+            return Err(MmrError::InvalidSize(keccak.size()));
+        }
+        Ok(DualMmr { poseidon, keccak })
+    }
+
+    /// Appends the Poseidon and Keccak hashes of the same block to their respective MMRs.
+    pub fn append(&mut self, poseidon_hash: Felt, keccak_hash: [u8; 32]) -> Result<(), MmrError> {
+        self.poseidon.append(poseidon_hash)?;
+        self.keccak.append(keccak_hash)
+    }
+
+    pub fn size(&self) -> usize {
+        self.poseidon.size()
+    }
+
+    pub fn poseidon(&self) -> &Mmr<Poseidon, P> {
+        &self.poseidon
+    }
+
+    pub fn keccak(&self) -> &Mmr<Keccak, K> {
+        &self.keccak
+    }
+
+    pub fn get_state(&self) -> Result<DualMmrState, MmrError> {
+        Ok(DualMmrState {
+            root_poseidon: self.poseidon.get_root()?,
+            root_keccak: self.keccak.get_root()?,
+            peaks_poseidon: self.poseidon.retrieve_nodes(self.poseidon.get_peaks())?,
+            peaks_keccak: self.keccak.retrieve_nodes(self.keccak.get_peaks())?,
+            size: self.size(),
+        })
+    }
+}
@@ -0,0 +1,261 @@
+use bincode::error::{DecodeError, EncodeError};
+use cairo_vm::vm::errors::hint_errors::HintError;
+use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
+use starknet_crypto::poseidon_hash;
+use starknet_types_core::felt::Felt;
+use std::fmt::Debug;
+use thiserror::Error;
+use tiny_keccak::{Hasher as KeccakTrait, Keccak as KeccakHasher};
+
+pub mod compact;
+pub mod dual;
+pub mod position;
+pub mod proof;
+pub mod snapshot;
+pub mod store;
+
+pub use compact::CompactMmr;
+pub use dual::{DualMmr, DualMmrState};
+pub use proof::{verify_batch_proof, verify_consistency, verify_proof, BatchProof, ConsistencyProof, Proof};
+pub use store::{FileStore, MemoryStore, Store};
+
+use position::sibling_offset;
+
+/// A 32-byte value produced by a `Hasher`, convertible from untyped integers at the boundary.
+pub trait Digest: Sized + Clone + PartialEq + Debug {
+    fn to_be_bytes(&self) -> [u8; 32];
+    fn try_from_be_bytes(bytes: [u8; 32]) -> Result<Self, MmrError>;
+    fn from_size(size: usize) -> Self;
+
+    fn to_biguint(&self) -> BigUint {
+        BigUint::from_bytes_be(&self.to_be_bytes())
+    }
+
+    fn try_from_biguint(value: &BigUint) -> Result<Self, MmrError> {
+        let bytes = value.to_bytes_be();
+        if bytes.len() > 32 {
+            return Err(MmrError::InvalidDigest(value.clone()));
+        }
+        let mut padded = [0u8; 32];
+        padded[32 - bytes.len()..].copy_from_slice(&bytes);
+        Self::try_from_be_bytes(padded)
+    }
+}
+
+impl Digest for Felt {
+    fn to_be_bytes(&self) -> [u8; 32] {
+        self.to_bytes_be()
+    }
+
+    fn try_from_be_bytes(bytes: [u8; 32]) -> Result<Self, MmrError> {
+        let felt = Felt::from_bytes_be(&bytes);
+        // `from_bytes_be` reduces modulo the field prime, reject values that do not round-trip.
+        if felt.to_bytes_be() != bytes {
+            return Err(MmrError::InvalidDigest(BigUint::from_bytes_be(&bytes)));
+        }
+        Ok(felt)
+    }
+
+    fn from_size(size: usize) -> Self {
+        Felt::from(size)
+    }
+}
+
+impl Digest for [u8; 32] {
+    fn to_be_bytes(&self) -> [u8; 32] {
+        *self
+    }
+
+    fn try_from_be_bytes(bytes: [u8; 32]) -> Result<Self, MmrError> {
+        Ok(bytes)
+    }
+
+    fn from_size(size: usize) -> Self {
+        let mut bytes = [0u8; 32];
+        bytes[24..].copy_from_slice(&(size as u64).to_be_bytes());
+        bytes
+    }
+}
+
+/// Identifies a `Hasher` in serialized data. Variants are encoded by position, only append new ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HasherKind {
+    Poseidon,
+    Keccak,
+}
+
+pub trait Hasher {
+    type Digest: Digest;
+    const KIND: HasherKind;
+
+    fn new() -> Self;
+    fn hash(&self, x: &Self::Digest, y: &Self::Digest) -> Self::Digest;
+}
+
+#[derive(Debug)]
+pub struct Keccak;
+impl Hasher for Keccak {
+    type Digest = [u8; 32];
+    const KIND: HasherKind = HasherKind::Keccak;
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn hash(&self, x: &[u8; 32], y: &[u8; 32]) -> [u8; 32] {
+        let mut keccak = KeccakHasher::v256();
+        keccak.update(x);
+        keccak.update(y);
+
+        let mut output = [0u8; 32];
+        keccak.finalize(&mut output);
+        output
+    }
+}
+
+#[derive(Debug)]
+pub struct Poseidon;
+impl Hasher for Poseidon {
+    type Digest = Felt;
+    const KIND: HasherKind = HasherKind::Poseidon;
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn hash(&self, x: &Felt, y: &Felt) -> Felt {
+        poseidon_hash(*x, *y)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum MmrError {
+    #[error("Failed to access the node store")]
+    Store(#[from] std::io::Error),
+    #[error("Leaf index {0} is out of range")]
+    LeafIndexOutOfRange(usize),
+    #[error("Cannot prove an empty set of leaves")]
+    EmptyBatch,
+    #[error("Invalid MMR size {0}")]
+    InvalidSize(usize),
+    #[error("An empty MMR has no root")]
+    EmptyMmr,
+    #[error("Value {0:#x} does not fit in the digest type")]
+    InvalidDigest(BigUint),
+    #[error("Failed to encode the snapshot")]
+    SnapshotEncode(#[from] EncodeError),
+    #[error("Failed to decode the snapshot")]
+    SnapshotDecode(#[from] DecodeError),
+    #[error("Malformed MMR snapshot")]
+    MalformedSnapshot,
+    #[error("Unsupported snapshot version {0}")]
+    UnsupportedSnapshotVersion(u16),
+    #[error("Snapshot was taken with the {found:?} hasher, expected {expected:?}")]
+    HasherMismatch { expected: HasherKind, found: HasherKind },
+    #[error("Snapshot only holds the peaks, not every node")]
+    MissingNodes,
+}
+
+impl From<MmrError> for HintError {
+    fn from(error: MmrError) -> Self {
+        HintError::CustomHint(error.to_string().into_boxed_str())
+    }
+}
+
+#[derive(Debug)]
+pub struct Mmr<H: Hasher, S: Store<H::Digest> = MemoryStore<<H as Hasher>::Digest>> {
+    hasher: H,
+    store: S,
+    leaf_count: usize,
+    /// Scratch buffer for the nodes created by an append, reused to keep `append` allocation-free.
+    new_nodes: Vec<H::Digest>,
+}
+
+impl<H: Hasher> Default for Mmr<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H: Hasher> Mmr<H> {
+    pub fn new() -> Mmr<H> {
+        Mmr {
+            hasher: H::new(),
+            store: MemoryStore::default(),
+            leaf_count: 0,
+            new_nodes: vec![],
+        }
+    }
+}
+
+impl<H: Hasher, S: Store<H::Digest>> Mmr<H, S> {
+    /// Resumes an MMR from the nodes already held by `store`.
+    pub fn with_store(store: S) -> Result<Mmr<H, S>, MmrError> {
+        let leaf_count = position::mmr_size_to_leaf_count(store.len()).ok_or(MmrError::InvalidSize(store.len()))?;
+        Ok(Mmr {
+            hasher: H::new(),
+            store,
+            leaf_count,
+            new_nodes: vec![],
+        })
+    }
+
+    pub fn get_root(&self) -> Result<H::Digest, MmrError> {
+        compute_root(&self.hasher, self.size(), &self.retrieve_nodes(self.get_peaks())?)
+    }
+
+    pub fn size(&self) -> usize {
+        self.store.len()
+    }
+
+    pub fn get_peaks(&self) -> Vec<usize> {
+        find_peaks(self.size()).expect("Invalid node count")
+    }
+
+    pub fn append(&mut self, element: H::Digest) -> Result<(), MmrError> {
+        // Each merge pairs the new node with its left sibling, located by position instead of
+        // collecting the peaks, so appending only touches the nodes it hashes.
+        let no_merged_peaks = self.leaf_count.trailing_ones();
+        let mut last_node = element;
+        self.new_nodes.clear();
+        for (height, index) in (0..no_merged_peaks).zip(self.size() + 1..) {
+            let left_sibling = self.store.get(index - sibling_offset(height) - 1)?;
+            let parent = self.hasher.hash(&left_sibling, &last_node);
+            self.new_nodes.push(last_node);
+            last_node = parent;
+        }
+        self.new_nodes.push(last_node);
+        self.store.extend(&self.new_nodes)?;
+        self.leaf_count += 1;
+        Ok(())
+    }
+
+    pub fn retrieve_nodes(&self, indices: Vec<usize>) -> Result<Vec<H::Digest>, MmrError> {
+        indices.iter().map(|index| Ok(self.store.get(*index - 1)?)).collect()
+    }
+}
+
+/// The root of the MMR of `size` nodes with the given peaks. Like `tools/py/mmr.py` and the Cairo programs,
+/// there is no root for the empty MMR.
+fn compute_root<H: Hasher>(hasher: &H, size: usize, peaks: &[H::Digest]) -> Result<H::Digest, MmrError> {
+    Ok(hasher.hash(&H::Digest::from_size(size), &bag_peaks(hasher, peaks).ok_or(MmrError::EmptyMmr)?))
+}
+
+fn bag_peaks<H: Hasher>(hasher: &H, peaks: &[H::Digest]) -> Option<H::Digest> {
+    let (last, rest) = peaks.split_last()?;
+    Some(rest.iter().rev().fold(last.clone(), |hash, peak| hasher.hash(peak, &hash)))
+}
+
+fn find_peaks(node_count: usize) -> Option<Vec<usize>> {
+    Some(position::get_peaks(node_count)?.into_iter().map(|pos| pos + 1).collect())
+}
+
+/// Height of the node at the 1-based `index`, leaves being at height 0.
+fn node_height(index: usize) -> u32 {
+    position::tree_pos_height(index - 1)
+}
+
+fn leaf_index_to_element_index(leaf_index: usize) -> usize {
+    position::leaf_index_to_pos(leaf_index) + 1
+}
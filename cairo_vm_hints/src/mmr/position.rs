@@ -0,0 +1,107 @@
+//! Node position arithmetic, mirroring the helpers of `tools/py/mmr.py`.
+//!
+//! Positions are 0-based as in the Python tooling. `Mmr` and the Cairo programs index nodes from 1,
+//! the index of a node being its position plus one.
+
+/// Height of the node at `pos`, leaves being at height 0.
+pub fn tree_pos_height(pos: usize) -> u32 {
+    // Work on the 1-based index, in which the leftmost node of each height is all one-bits.
+    let mut index = pos + 1;
+    while (index + 1).count_ones() != 1 {
+        index -= (1 << index.ilog2()) - 1;
+    }
+    index.ilog2()
+}
+
+/// Distance between a node of the given height and its sibling.
+pub fn sibling_offset(height: u32) -> usize {
+    (2 << height) - 1
+}
+
+/// Height and position of the leftmost peak of an MMR of `mmr_size` nodes, `None` for an empty MMR.
+pub fn left_peak_height_pos(mmr_size: usize) -> Option<(u32, usize)> {
+    if mmr_size == 0 {
+        return None;
+    }
+    let height = (mmr_size + 1).ilog2() - 1;
+    Some((height, (2 << height) - 2))
+}
+
+/// Positions of the peaks from left to right, `None` if `mmr_size` is not a valid size.
+pub fn get_peaks(mut mmr_size: usize) -> Option<Vec<usize>> {
+    let mut peaks = vec![];
+    let Some((height, _)) = left_peak_height_pos(mmr_size) else {
+        return Some(peaks);
+    };
+    let mut offset = 0;
+    for h in (0..=height).rev() {
+        let subtree_size = (2 << h) - 1;
+        if subtree_size <= mmr_size {
+            mmr_size -= subtree_size;
+            offset += subtree_size;
+            peaks.push(offset - 1);
+        }
+    }
+    (mmr_size == 0).then_some(peaks)
+}
+
+/// Whether an MMR can hold exactly `mmr_size` nodes. Unlike the Python helper, and like the Cairo
+/// `assert_mmr_size_is_valid`, the empty MMR is rejected.
+pub fn is_valid_mmr_size(mut mmr_size: usize) -> bool {
+    let Some((height, _)) = left_peak_height_pos(mmr_size) else {
+        return false;
+    };
+    for h in (0..=height).rev() {
+        let subtree_size = (2 << h) - 1;
+        if subtree_size <= mmr_size {
+            mmr_size -= subtree_size;
+        }
+    }
+    mmr_size == 0
+}
+
+/// Position of the `leaf_index`-th leaf (0-based).
+pub fn leaf_index_to_pos(leaf_index: usize) -> usize {
+    // The nodes before a leaf form the MMR of the leaves preceding it.
+    leaf_count_to_mmr_size(leaf_index)
+}
+
+/// Leaf index of the node at `pos`, `None` if it is not a leaf.
+pub fn pos_to_leaf_index(pos: usize) -> Option<usize> {
+    if tree_pos_height(pos) != 0 {
+        return None;
+    }
+    mmr_size_to_leaf_count(pos)
+}
+
+/// Number of nodes of an MMR holding `leaf_count` leaves.
+pub fn leaf_count_to_mmr_size(leaf_count: usize) -> usize {
+    2 * leaf_count - leaf_count.count_ones() as usize
+}
+
+/// Number of leaves of an MMR of `mmr_size` nodes, `None` if `mmr_size` is not a valid size.
+pub fn mmr_size_to_leaf_count(mmr_size: usize) -> Option<usize> {
+    let mut previous_size = 0;
+    let mut leaf_count = 0;
+    for pos in get_peaks(mmr_size)? {
+        leaf_count += (pos + 1 - previous_size).div_ceil(2);
+        previous_size = pos + 1;
+    }
+    Some(leaf_count)
+}
+
+/// Position of the left child of the node at `pos`, `None` for a leaf.
+pub fn left_child(pos: usize) -> Option<usize> {
+    match tree_pos_height(pos) {
+        0 => None,
+        height => Some(pos - (1 << height)),
+    }
+}
+
+/// Position of the right child of the node at `pos`, `None` for a leaf.
+pub fn right_child(pos: usize) -> Option<usize> {
+    match tree_pos_height(pos) {
+        0 => None,
+        _ => Some(pos - 1),
+    }
+}
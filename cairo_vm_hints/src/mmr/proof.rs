@@ -0,0 +1,256 @@
+use super::position::sibling_offset;
+use super::{compute_root, find_peaks, leaf_index_to_element_index, node_height, Hasher, Mmr, MmrError, Store};
+
+/// Inclusion proof of a single leaf: the siblings up to its peak, followed by every peak of the MMR.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Proof<D> {
+    pub element_index: usize,
+    pub siblings: Vec<D>,
+    pub peaks: Vec<D>,
+}
+
+/// Inclusion proof of several leaves against the same root. Siblings shared between their paths, or
+/// computable from the proven leaves, are included only once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchProof<D> {
+    /// Indices of the proven leaves, strictly increasing.
+    pub element_indices: Vec<usize>,
+    pub siblings: Vec<D>,
+    pub peaks: Vec<D>,
+}
+
+/// Proof that an MMR extends a smaller one: each old peak is proven against the new peaks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsistencyProof<D> {
+    pub old_peaks: Vec<D>,
+    pub paths: Vec<Vec<D>>,
+    pub new_peaks: Vec<D>,
+}
+
+impl<H: Hasher, S: Store<H::Digest>> Mmr<H, S> {
+    /// Builds an inclusion proof for the `leaf_index`-th appended leaf (0-based).
+    pub fn get_proof(&self, leaf_index: usize) -> Result<Proof<H::Digest>, MmrError> {
+        if leaf_index >= self.leaf_count {
+            return Err(MmrError::LeafIndexOutOfRange(leaf_index));
+        }
+
+        let element_index = leaf_index_to_element_index(leaf_index);
+        Ok(Proof {
+            element_index,
+            siblings: self.path_to_peak(element_index)?,
+            peaks: self.retrieve_nodes(self.get_peaks())?,
+        })
+    }
+
+    /// Builds a single proof for the `leaf_indices`-th appended leaves (0-based, in any order and
+    /// possibly repeated). The leaves are proven in increasing order, see `BatchProof::element_indices`.
+    pub fn get_batch_proof(&self, leaf_indices: &[usize]) -> Result<BatchProof<H::Digest>, MmrError> {
+        if leaf_indices.is_empty() {
+            return Err(MmrError::EmptyBatch);
+        }
+        if let Some(leaf_index) = leaf_indices.iter().find(|leaf_index| **leaf_index >= self.leaf_count) {
+            return Err(MmrError::LeafIndexOutOfRange(*leaf_index));
+        }
+
+        let mut element_indices = leaf_indices
+            .iter()
+            .map(|leaf_index| leaf_index_to_element_index(*leaf_index))
+            .collect::<Vec<_>>();
+        element_indices.sort_unstable();
+        element_indices.dedup();
+
+        // Walk up one height at a time, so that a sibling is only requested once it is known that
+        // none of the proven leaves lies below it.
+        let mut siblings = vec![];
+        let mut layer = element_indices.clone();
+        let mut height = 0;
+        while !layer.is_empty() {
+            let mut next_layer = vec![];
+            let mut nodes = layer.iter().peekable();
+            while let Some(&index) = nodes.next() {
+                if node_height(index + 1) > height {
+                    siblings.push(self.store.get(index - sibling_offset(height) - 1)?);
+                    next_layer.push(index + 1);
+                } else {
+                    let sibling = index + sibling_offset(height);
+                    if sibling > self.size() {
+                        continue;
+                    }
+                    if nodes.next_if_eq(&&sibling).is_none() {
+                        siblings.push(self.store.get(sibling - 1)?);
+                    }
+                    next_layer.push(sibling + 1);
+                }
+            }
+            layer = next_layer;
+            height += 1;
+        }
+
+        Ok(BatchProof {
+            element_indices,
+            siblings,
+            peaks: self.retrieve_nodes(self.get_peaks())?,
+        })
+    }
+
+    /// Builds a proof that the current MMR is an append-only extension of its state at `old_size` nodes.
+    pub fn get_consistency_proof(&self, old_size: usize) -> Result<ConsistencyProof<H::Digest>, MmrError> {
+        let old_peak_indices = match find_peaks(old_size) {
+            Some(peak_indices) if old_size != 0 && old_size <= self.size() => peak_indices,
+            _ => return Err(MmrError::InvalidSize(old_size)),
+        };
+
+        Ok(ConsistencyProof {
+            paths: old_peak_indices.iter().map(|index| self.path_to_peak(*index)).collect::<Result<_, _>>()?,
+            old_peaks: self.retrieve_nodes(old_peak_indices)?,
+            new_peaks: self.retrieve_nodes(self.get_peaks())?,
+        })
+    }
+
+    fn path_to_peak(&self, mut index: usize) -> Result<Vec<H::Digest>, MmrError> {
+        let mut siblings = vec![];
+        let mut height = node_height(index);
+        loop {
+            if node_height(index + 1) > height {
+                siblings.push(self.store.get(index - sibling_offset(height) - 1)?);
+                index += 1;
+            } else {
+                let sibling = index + sibling_offset(height);
+                if sibling > self.size() {
+                    break;
+                }
+                siblings.push(self.store.get(sibling - 1)?);
+                index += 2 << height;
+            }
+            height += 1;
+        }
+        Ok(siblings)
+    }
+}
+
+/// Verifies that `leaf` is included in the MMR of `size` nodes committed to by `root`.
+pub fn verify_proof<H: Hasher>(root: &H::Digest, size: usize, leaf: &H::Digest, proof: &Proof<H::Digest>) -> bool {
+    if proof.element_index == 0 || proof.element_index > size || node_height(proof.element_index) != 0 {
+        return false;
+    }
+
+    let peak_indices = match find_peaks(size) {
+        Some(peak_indices) if peak_indices.len() == proof.peaks.len() => peak_indices,
+        _ => return false,
+    };
+
+    let hasher = H::new();
+    is_under_peaks(&hasher, leaf, proof.element_index, &proof.siblings, &peak_indices, &proof.peaks)
+        && compute_root(&hasher, size, &proof.peaks).is_ok_and(|computed| computed == *root)
+}
+
+/// Verifies that `leaves`, given in the order of `proof.element_indices`, are included in the MMR of
+/// `size` nodes committed to by `root`.
+pub fn verify_batch_proof<H: Hasher>(root: &H::Digest, size: usize, leaves: &[H::Digest], proof: &BatchProof<H::Digest>) -> bool {
+    if leaves.is_empty() || leaves.len() != proof.element_indices.len() {
+        return false;
+    }
+    if !proof.element_indices.windows(2).all(|pair| pair[0] < pair[1])
+        || proof
+            .element_indices
+            .iter()
+            .any(|index| *index == 0 || *index > size || node_height(*index) != 0)
+    {
+        return false;
+    }
+
+    let peak_indices = match find_peaks(size) {
+        Some(peak_indices) if peak_indices.len() == proof.peaks.len() => peak_indices,
+        _ => return false,
+    };
+
+    let hasher = H::new();
+    let mut siblings = proof.siblings.iter();
+    let mut layer = proof.element_indices.iter().copied().zip(leaves.iter().cloned()).collect::<Vec<_>>();
+    let mut height = 0;
+    while !layer.is_empty() {
+        let mut next_layer = vec![];
+        let mut nodes = layer.into_iter().peekable();
+        while let Some((index, hash)) = nodes.next() {
+            if node_height(index + 1) > height {
+                let Some(sibling) = siblings.next() else {
+                    return false;
+                };
+                next_layer.push((index + 1, hasher.hash(sibling, &hash)));
+            } else {
+                let sibling_index = index + sibling_offset(height);
+                if sibling_index > size {
+                    match peak_indices.iter().position(|peak| *peak == index) {
+                        Some(position) if proof.peaks[position] == hash => continue,
+                        _ => return false,
+                    }
+                }
+                let sibling = match nodes.next_if(|(next_index, _)| *next_index == sibling_index) {
+                    Some((_, sibling)) => sibling,
+                    None => match siblings.next() {
+                        Some(sibling) => sibling.clone(),
+                        None => return false,
+                    },
+                };
+                next_layer.push((sibling_index + 1, hasher.hash(&hash, &sibling)));
+            }
+        }
+        layer = next_layer;
+        height += 1;
+    }
+
+    siblings.next().is_none() && compute_root(&hasher, size, &proof.peaks).is_ok_and(|computed| computed == *root)
+}
+
+/// Verifies that the MMR of `new_size` nodes committed to by `new_root` extends the one of `old_size` nodes committed to by `old_root`.
+pub fn verify_consistency<H: Hasher>(
+    old_root: &H::Digest,
+    old_size: usize,
+    new_root: &H::Digest,
+    new_size: usize,
+    proof: &ConsistencyProof<H::Digest>,
+) -> bool {
+    if old_size == 0 || old_size > new_size || proof.paths.len() != proof.old_peaks.len() {
+        return false;
+    }
+
+    let old_peak_indices = match find_peaks(old_size) {
+        Some(peak_indices) if peak_indices.len() == proof.old_peaks.len() => peak_indices,
+        _ => return false,
+    };
+    let new_peak_indices = match find_peaks(new_size) {
+        Some(peak_indices) if peak_indices.len() == proof.new_peaks.len() => peak_indices,
+        _ => return false,
+    };
+
+    let hasher = H::new();
+    compute_root(&hasher, old_size, &proof.old_peaks).is_ok_and(|computed| computed == *old_root)
+        && old_peak_indices
+            .iter()
+            .zip(proof.old_peaks.iter().zip(proof.paths.iter()))
+            .all(|(index, (peak, path))| is_under_peaks(&hasher, peak, *index, path, &new_peak_indices, &proof.new_peaks))
+        && compute_root(&hasher, new_size, &proof.new_peaks).is_ok_and(|computed| computed == *new_root)
+}
+
+/// Hashes `node` up along `siblings` and checks that the result is the peak found at the reached index.
+fn is_under_peaks<H: Hasher>(
+    hasher: &H,
+    node: &H::Digest,
+    mut index: usize,
+    siblings: &[H::Digest],
+    peak_indices: &[usize],
+    peaks: &[H::Digest],
+) -> bool {
+    let mut hash = node.clone();
+    for (height, sibling) in (node_height(index)..).zip(siblings) {
+        if node_height(index + 1) > height {
+            hash = hasher.hash(sibling, &hash);
+            index += 1;
+        } else {
+            hash = hasher.hash(&hash, sibling);
+            index += 2 << height;
+        }
+    }
+
+    matches!(peak_indices.iter().position(|peak| *peak == index), Some(position) if peaks[position] == hash)
+}
@@ -0,0 +1,131 @@
+use bincode::config::{self, Configuration};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use super::{find_peaks, CompactMmr, Digest, Hasher, HasherKind, MemoryStore, Mmr, MmrError, Store};
+
+/// Version written in the header of every snapshot, to be bumped on any change to the layout below.
+pub const SNAPSHOT_VERSION: u16 = 1;
+
+const SNAPSHOT_MAGIC: [u8; 4] = *b"MMRS";
+
+/// Snapshot header, encoded on its own so that the version can be checked before decoding the body.
+#[derive(Debug, Serialize, Deserialize)]
+struct Header {
+    magic: [u8; 4],
+    version: u16,
+    hasher: HasherKind,
+    content: Content,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum Content {
+    /// Every node of the MMR, in insertion order.
+    Nodes,
+    /// Only the peaks of the MMR, from left to right.
+    Peaks,
+}
+
+/// Snapshot body, digests being stored as their 32-byte big-endian encoding.
+#[derive(Debug, Serialize, Deserialize)]
+struct Body {
+    size: u64,
+    nodes: Vec<[u8; 32]>,
+}
+
+fn bincode_config() -> Configuration {
+    config::standard()
+}
+
+fn encode<H: Hasher>(content: Content, size: usize, nodes: &[H::Digest]) -> Result<Vec<u8>, MmrError> {
+    let header = Header {
+        magic: SNAPSHOT_MAGIC,
+        version: SNAPSHOT_VERSION,
+        hasher: H::KIND,
+        content,
+    };
+    let body = Body {
+        size: size as u64,
+        nodes: nodes.iter().map(Digest::to_be_bytes).collect(),
+    };
+
+    let mut bytes = bincode::serde::encode_to_vec(&header, bincode_config())?;
+    bytes.extend(bincode::serde::encode_to_vec(&body, bincode_config())?);
+    Ok(bytes)
+}
+
+fn decode<H: Hasher>(bytes: &[u8]) -> Result<(Content, usize, Vec<H::Digest>), MmrError> {
+    let (header, header_len) = decode_part::<Header>(bytes)?;
+    if header.magic != SNAPSHOT_MAGIC {
+        return Err(MmrError::MalformedSnapshot);
+    }
+    if header.version != SNAPSHOT_VERSION {
+        return Err(MmrError::UnsupportedSnapshotVersion(header.version));
+    }
+    if header.hasher != H::KIND {
+        return Err(MmrError::HasherMismatch {
+            expected: H::KIND,
+            found: header.hasher,
+        });
+    }
+
+    let (body, body_len) = decode_part::<Body>(&bytes[header_len..])?;
+    if header_len + body_len != bytes.len() {
+        return Err(MmrError::MalformedSnapshot);
+    }
+    let size = usize::try_from(body.size).map_err(|_| MmrError::MalformedSnapshot)?;
+    let nodes = body.nodes.into_iter().map(H::Digest::try_from_be_bytes).collect::<Result<_, _>>()?;
+    Ok((header.content, size, nodes))
+}
+
+fn decode_part<T: DeserializeOwned>(bytes: &[u8]) -> Result<(T, usize), MmrError> {
+    Ok(bincode::serde::decode_from_slice(bytes, bincode_config())?)
+}
+
+impl<H: Hasher, S: Store<H::Digest>> Mmr<H, S> {
+    /// Encodes every node of the MMR into a snapshot that `Mmr::from_snapshot` loads back.
+    pub fn to_snapshot(&self) -> Result<Vec<u8>, MmrError> {
+        let nodes = (0..self.size()).map(|index| self.store.get(index)).collect::<Result<Vec<_>, _>>()?;
+        encode::<H>(Content::Nodes, self.size(), &nodes)
+    }
+}
+
+impl<H: Hasher> Mmr<H> {
+    /// Restores an in-memory MMR from a snapshot taken with `Mmr::to_snapshot`.
+    pub fn from_snapshot(bytes: &[u8]) -> Result<Mmr<H>, MmrError> {
+        let (content, size, nodes) = decode::<H>(bytes)?;
+        if content != Content::Nodes {
+            return Err(MmrError::MissingNodes);
+        }
+        if nodes.len() != size {
+            return Err(MmrError::MalformedSnapshot);
+        }
+
+        let mut store = MemoryStore::default();
+        store.extend(&nodes)?;
+        Mmr::with_store(store)
+    }
+}
+
+impl<H: Hasher> CompactMmr<H> {
+    /// Encodes the peaks of the MMR into a snapshot that `CompactMmr::from_snapshot` loads back.
+    pub fn to_snapshot(&self) -> Result<Vec<u8>, MmrError> {
+        encode::<H>(Content::Peaks, self.size(), self.get_peak_values())
+    }
+
+    /// Restores the peaks of an MMR from a snapshot taken with either `CompactMmr::to_snapshot`
+    /// or `Mmr::to_snapshot`.
+    pub fn from_snapshot(bytes: &[u8]) -> Result<CompactMmr<H>, MmrError> {
+        let (content, size, nodes) = decode::<H>(bytes)?;
+        let peaks = match content {
+            Content::Peaks => nodes,
+            Content::Nodes => {
+                if nodes.len() != size {
+                    return Err(MmrError::MalformedSnapshot);
+                }
+                let peak_indices = find_peaks(size).ok_or(MmrError::InvalidSize(size))?;
+                peak_indices.into_iter().map(|index| nodes[index - 1].clone()).collect()
+            }
+        };
+        CompactMmr::from_peaks(size, peaks).ok_or(MmrError::MalformedSnapshot)
+    }
+}
@@ -0,0 +1,246 @@
+use super::position::{is_valid_mmr_size, leaf_count_to_mmr_size, mmr_size_to_leaf_count};
+use super::{Digest, Hasher, HasherKind};
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::path::Path;
+
+/// Size of a serialized node in a `FileStore`, nodes being stored as their 32-byte big-endian digest.
+const NODE_SIZE: usize = 32;
+/// Size of a node record in a `FileStore`, the node followed by its checksum.
+const RECORD_SIZE: usize = NODE_SIZE + 4;
+/// Size of the `FileStore` header: magic, version, hasher and a reserved byte.
+const HEADER_SIZE: usize = 8;
+
+/// Version written in the header of every node file, to be bumped on any change to its layout.
+pub const FILE_VERSION: u16 = 1;
+
+const FILE_MAGIC: [u8; 4] = *b"MMRN";
+
+/// Backend holding the nodes of an `Mmr`, indexed from 0 in insertion order.
+pub trait Store<D: Digest> {
+    fn len(&self) -> usize;
+    fn get(&self, index: usize) -> io::Result<D>;
+    fn extend(&mut self, nodes: &[D]) -> io::Result<()>;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[derive(Debug)]
+pub struct MemoryStore<D> {
+    nodes: Vec<D>,
+}
+
+impl<D> Default for MemoryStore<D> {
+    fn default() -> Self {
+        MemoryStore { nodes: vec![] }
+    }
+}
+
+impl<D: Digest> Store<D> for MemoryStore<D> {
+    fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn get(&self, index: usize) -> io::Result<D> {
+        self.nodes.get(index).cloned().ok_or_else(|| out_of_range(index))
+    }
+
+    fn extend(&mut self, nodes: &[D]) -> io::Result<()> {
+        self.nodes.extend_from_slice(nodes);
+        Ok(())
+    }
+}
+
+/// Append-only node file. Every `extend` is synced to disk before returning, and the nodes of an `extend`
+/// that did not complete, whether missing, torn or zero-filled, are discarded when the file is reopened.
+///
+/// The file starts with a header naming the hasher of the MMR, followed by one record per node: its
+/// 32-byte big-endian digest and a checksum of the digest and of the node index.
+#[derive(Debug)]
+pub struct FileStore<D> {
+    file: File,
+    len: usize,
+    digest: PhantomData<D>,
+}
+
+impl<D: Digest> FileStore<D> {
+    /// Opens the node file of an MMR hashed with `H`, creating it if it does not exist.
+    pub fn open<H: Hasher<Digest = D>>(path: impl AsRef<Path>) -> io::Result<FileStore<D>> {
+        let path = path.as_ref();
+        let header = file_header(H::KIND);
+        let mut file = match OpenOptions::new().read(true).append(true).create_new(true).open(path) {
+            Ok(mut file) => {
+                file.write_all(&header)?;
+                file.sync_all()?;
+                sync_parent_dir(path)?;
+                file
+            }
+            Err(error) if error.kind() == io::ErrorKind::AlreadyExists => OpenOptions::new().read(true).append(true).open(path)?,
+            Err(error) => return Err(error),
+        };
+
+        let file_len = file.metadata()?.len() as usize;
+        if file_len < HEADER_SIZE {
+            // Only a creation that did not complete leaves a partial header behind.
+            let mut written = vec![];
+            file.read_to_end(&mut written)?;
+            if !header.starts_with(&written) {
+                return Err(invalid_data("Not an MMR node file"));
+            }
+            file.set_len(0)?;
+            file.write_all(&header)?;
+            file.sync_all()?;
+        } else {
+            let mut found = [0u8; HEADER_SIZE];
+            file.seek(SeekFrom::Start(0))?;
+            file.read_exact(&mut found)?;
+            check_file_header(&found, H::KIND)?;
+        }
+
+        let mut store = FileStore {
+            file,
+            len: file_len.saturating_sub(HEADER_SIZE) / RECORD_SIZE,
+            digest: PhantomData,
+        };
+        store.roll_back()?;
+        Ok(store)
+    }
+
+    /// Rolls back to the last append whose records are all intact, which always leaves the MMR at a valid size.
+    fn roll_back(&mut self) -> io::Result<()> {
+        loop {
+            while self.len != 0 && !is_valid_mmr_size(self.len) {
+                self.len -= 1;
+            }
+            let Some(leaf_count) = mmr_size_to_leaf_count(self.len).filter(|leaf_count| *leaf_count != 0) else {
+                break;
+            };
+            // The nodes written by the append of the last leaf.
+            let previous_len = leaf_count_to_mmr_size(leaf_count - 1);
+            if self.is_intact(previous_len..self.len)? {
+                break;
+            }
+            self.len = previous_len;
+        }
+        self.file.set_len(record_offset(self.len))?;
+        self.file.sync_all()
+    }
+
+    /// Whether the records of `indices` match their checksum and hold a valid digest. Read errors are returned
+    /// rather than taken for corruption, so that they do not roll back intact nodes.
+    fn is_intact(&self, indices: Range<usize>) -> io::Result<bool> {
+        for index in indices {
+            match self.get(index) {
+                Ok(_) => {}
+                Err(error) if error.kind() == io::ErrorKind::InvalidData => return Ok(false),
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(true)
+    }
+}
+
+impl<D: Digest> Store<D> for FileStore<D> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn get(&self, index: usize) -> io::Result<D> {
+        if index >= self.len {
+            return Err(out_of_range(index));
+        }
+
+        let mut record = [0u8; RECORD_SIZE];
+        let mut file = &self.file;
+        file.seek(SeekFrom::Start(record_offset(index)))?;
+        file.read_exact(&mut record)?;
+
+        let (node, checksum) = record.split_at(NODE_SIZE);
+        let node: [u8; NODE_SIZE] = node.try_into().expect("Records start with a node");
+        if checksum != record_checksum(index, &node).to_le_bytes() {
+            return Err(invalid_data(format!("Node {} does not match its checksum", index)));
+        }
+        D::try_from_be_bytes(node).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    fn extend(&mut self, nodes: &[D]) -> io::Result<()> {
+        let buffer = (self.len..)
+            .zip(nodes)
+            .flat_map(|(index, node)| {
+                let node = node.to_be_bytes();
+                node.into_iter().chain(record_checksum(index, &node).to_le_bytes())
+            })
+            .collect::<Vec<_>>();
+
+        if let Err(error) = self.file.write_all(&buffer).and_then(|_| self.file.sync_data()) {
+            self.file.set_len(record_offset(self.len))?;
+            return Err(error);
+        }
+        self.len += nodes.len();
+        Ok(())
+    }
+}
+
+fn file_header(hasher: HasherKind) -> [u8; HEADER_SIZE] {
+    let mut header = [0u8; HEADER_SIZE];
+    header[..4].copy_from_slice(&FILE_MAGIC);
+    header[4..6].copy_from_slice(&FILE_VERSION.to_le_bytes());
+    header[6] = hasher as u8;
+    header
+}
+
+fn check_file_header(header: &[u8; HEADER_SIZE], hasher: HasherKind) -> io::Result<()> {
+    if header[..4] != FILE_MAGIC {
+        return Err(invalid_data("Not an MMR node file"));
+    }
+    let version = u16::from_le_bytes([header[4], header[5]]);
+    if version != FILE_VERSION {
+        return Err(invalid_data(format!("Unsupported MMR node file version {}", version)));
+    }
+    if header[6] != hasher as u8 {
+        return Err(invalid_data(format!("MMR node file was not written with the {:?} hasher", hasher)));
+    }
+    Ok(())
+}
+
+fn record_offset(index: usize) -> u64 {
+    (HEADER_SIZE + index * RECORD_SIZE) as u64
+}
+
+/// CRC-32 of the node index and digest, so that a zero-filled record or one written at another index is rejected.
+fn record_checksum(index: usize, node: &[u8; NODE_SIZE]) -> u32 {
+    let mut crc = !0u32;
+    for byte in (index as u64).to_le_bytes().iter().chain(node) {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xEDB8_8320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
+
+/// Makes the creation of the file at `path` durable, which syncing the file alone does not.
+#[cfg(unix)]
+fn sync_parent_dir(path: &Path) -> io::Result<()> {
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => File::open(parent)?.sync_all(),
+        _ => File::open(".")?.sync_all(),
+    }
+}
+
+#[cfg(not(unix))]
+fn sync_parent_dir(_path: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+fn invalid_data(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+fn out_of_range(index: usize) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, format!("Node {} is out of range", index))
+}
@@ -0,0 +1,166 @@
+use serde::{de::Error, Deserialize, Deserializer, Serialize};
+use std::path::Path;
+
+use super::{bytes_to_le_chunks, rlp, GetProofError, Uint256};
+
+/// The result of an `eth_getProof` call, byte strings being decoded from their `0x`-prefixed hex
+/// and quantities to their minimal big-endian bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetProofResponse {
+    #[serde(deserialize_with = "hex_bytes")]
+    pub address: Vec<u8>,
+    #[serde(deserialize_with = "hex_list")]
+    pub account_proof: Vec<Vec<u8>>,
+    #[serde(deserialize_with = "hex_quantity")]
+    pub balance: Vec<u8>,
+    #[serde(deserialize_with = "hex_word")]
+    pub code_hash: [u8; 32],
+    #[serde(deserialize_with = "hex_quantity")]
+    pub nonce: Vec<u8>,
+    #[serde(deserialize_with = "hex_word")]
+    pub storage_hash: [u8; 32],
+    pub storage_proof: Vec<StorageProofResponse>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct StorageProofResponse {
+    /// The slot, left-padded to 32 bytes.
+    #[serde(deserialize_with = "hex_word")]
+    pub key: [u8; 32],
+    #[serde(deserialize_with = "hex_quantity")]
+    pub value: Vec<u8>,
+    #[serde(deserialize_with = "hex_list")]
+    pub proof: Vec<Vec<u8>>,
+}
+
+/// An account proof in the layout of `CairoAccountMPTProof` in `tools/py/storage_proof.py`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountProofInput {
+    /// The state root as a little-endian `Uint256`, serialized as a `[low, high]` pair as by Python.
+    #[serde(with = "uint256_pair")]
+    pub root: Uint256,
+    /// The address as little-endian 8-byte chunks.
+    pub address: Vec<u64>,
+    /// The proof nodes as little-endian 8-byte chunks.
+    pub proof: Vec<Vec<u64>>,
+    pub proof_len: usize,
+    pub proof_bytes_len: Vec<usize>,
+}
+
+/// A storage proof in the same layout as `AccountProofInput`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageProofInput {
+    /// The storage root of the account as a little-endian `Uint256`.
+    #[serde(with = "uint256_pair")]
+    pub root: Uint256,
+    /// The 32-byte slot as little-endian 8-byte chunks.
+    pub slot: Vec<u64>,
+    pub proof: Vec<Vec<u64>>,
+    pub proof_len: usize,
+    pub proof_bytes_len: Vec<usize>,
+}
+
+/// Program input holding the account proof and the storage proofs of an `eth_getProof` response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GetProofInput {
+    pub account: AccountProofInput,
+    pub storage: Vec<StorageProofInput>,
+}
+
+impl GetProofResponse {
+    /// Reads a response saved as the JSON-RPC reply, or as its `result` alone.
+    pub fn from_file(path: &Path) -> Result<GetProofResponse, GetProofError> {
+        GetProofResponse::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn from_json(json: &str) -> Result<GetProofResponse, GetProofError> {
+        let mut value: serde_json::Value = serde_json::from_str(json)?;
+        if let Some(result) = value.get_mut("result") {
+            value = result.take();
+        }
+        Ok(serde_json::from_value(value)?)
+    }
+
+    /// The account proof against `state_root`, taken from the block header as the response does not hold it.
+    pub fn account_proof_input(&self, state_root: [u8; 32]) -> AccountProofInput {
+        AccountProofInput {
+            root: Uint256::from_le_bytes(state_root),
+            address: bytes_to_le_chunks(&self.address),
+            proof: self.account_proof.iter().map(|node| bytes_to_le_chunks(node)).collect(),
+            proof_len: self.account_proof.len(),
+            proof_bytes_len: self.account_proof.iter().map(Vec::len).collect(),
+        }
+    }
+
+    /// The storage proofs against the storage root of the account.
+    pub fn storage_proof_inputs(&self) -> Vec<StorageProofInput> {
+        self.storage_proof
+            .iter()
+            .map(|storage_proof| StorageProofInput {
+                root: Uint256::from_le_bytes(self.storage_hash),
+                slot: bytes_to_le_chunks(&storage_proof.key),
+                proof: storage_proof.proof.iter().map(|node| bytes_to_le_chunks(node)).collect(),
+                proof_len: storage_proof.proof.len(),
+                proof_bytes_len: storage_proof.proof.iter().map(Vec::len).collect(),
+            })
+            .collect()
+    }
+
+    pub fn program_input(&self, state_root: [u8; 32]) -> GetProofInput {
+        GetProofInput {
+            account: self.account_proof_input(state_root),
+            storage: self.storage_proof_inputs(),
+        }
+    }
+}
+
+/// Decodes `0x`-prefixed hex, odd lengths being left-padded as for JSON-RPC quantities.
+pub fn decode_hex(hex_str: &str) -> Result<Vec<u8>, hex::FromHexError> {
+    let digits = hex_str.strip_prefix("0x").unwrap_or(hex_str);
+    if digits.len() % 2 == 1 {
+        hex::decode(format!("0{digits}"))
+    } else {
+        hex::decode(digits)
+    }
+}
+
+fn hex_bytes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    decode_hex(&String::deserialize(deserializer)?).map_err(D::Error::custom)
+}
+
+fn hex_quantity<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    Ok(rlp::trim_leading_zeroes(&hex_bytes(deserializer)?).to_vec())
+}
+
+fn hex_word<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[u8; 32], D::Error> {
+    let bytes = hex_bytes(deserializer)?;
+    let start = 32usize
+        .checked_sub(bytes.len())
+        .ok_or_else(|| D::Error::custom(format!("expected at most 32 bytes, found {}", bytes.len())))?;
+    let mut word = [0u8; 32];
+    word[start..].copy_from_slice(&bytes);
+    Ok(word)
+}
+
+fn hex_list<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Vec<u8>>, D::Error> {
+    Vec::<String>::deserialize(deserializer)?
+        .iter()
+        .map(|item| decode_hex(item).map_err(D::Error::custom))
+        .collect()
+}
+
+mod uint256_pair {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::mpt::Uint256;
+
+    pub fn serialize<S: Serializer>(value: &Uint256, serializer: S) -> Result<S::Ok, S::Error> {
+        (value.low, value.high).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uint256, D::Error> {
+        let (low, high) = <(u128, u128)>::deserialize(deserializer)?;
+        Ok(Uint256 { low, high })
+    }
+}
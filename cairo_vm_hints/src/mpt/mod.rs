@@ -0,0 +1,205 @@
+use cairo_vm::vm::errors::hint_errors::HintError;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tiny_keccak::{Hasher, Keccak};
+
+pub mod get_proof;
+pub mod node;
+pub mod proof;
+pub mod rlp;
+pub mod storage;
+pub mod trie;
+pub mod verify;
+
+pub use get_proof::{AccountProofInput, GetProofInput, GetProofResponse, StorageProofInput};
+pub use node::{Child, Node};
+pub use proof::{empty_root, verify_proof};
+pub use storage::{verify_account_proof, verify_storage_proof, Account};
+pub use trie::{hashed_key, key_from_index, MptProof, ProofKind, Trie};
+pub use verify::{verify_mpt_proof, MptValue};
+
+/// A 256-bit number split in two 128-bit limbs, as Cairo's `Uint256`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Uint256 {
+    pub low: u128,
+    pub high: u128,
+}
+
+impl Uint256 {
+    pub fn from_be_bytes(bytes: [u8; 32]) -> Uint256 {
+        let (high, low) = bytes.split_at(16);
+        Uint256 {
+            low: u128::from_be_bytes(low.try_into().unwrap()),
+            high: u128::from_be_bytes(high.try_into().unwrap()),
+        }
+    }
+
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        bytes[..16].copy_from_slice(&self.high.to_be_bytes());
+        bytes[16..].copy_from_slice(&self.low.to_be_bytes());
+        bytes
+    }
+
+    /// Reads a 32-byte string the way Cairo's keccak returns it: `low` holds the first 16 bytes,
+    /// both limbs being little-endian.
+    pub fn from_le_bytes(bytes: [u8; 32]) -> Uint256 {
+        let (low, high) = bytes.split_at(16);
+        Uint256 {
+            low: u128::from_le_bytes(low.try_into().unwrap()),
+            high: u128::from_le_bytes(high.try_into().unwrap()),
+        }
+    }
+
+    pub fn to_le_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        bytes[..16].copy_from_slice(&self.low.to_le_bytes());
+        bytes[16..].copy_from_slice(&self.high.to_le_bytes());
+        bytes
+    }
+}
+
+/// Packs bytes into the little-endian 8-byte chunks used by the Cairo programs, the last chunk
+/// holding the remaining bytes.
+pub fn bytes_to_le_chunks(bytes: &[u8]) -> Vec<u64> {
+    bytes
+        .chunks(8)
+        .map(|chunk| {
+            let mut word = [0u8; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            u64::from_le_bytes(word)
+        })
+        .collect()
+}
+
+/// Unpacks the first `bytes_len` bytes of little-endian 8-byte chunks.
+pub fn le_chunks_to_bytes(chunks: &[u64], bytes_len: usize) -> Option<Vec<u8>> {
+    let mut bytes = chunks.iter().flat_map(|chunk| chunk.to_le_bytes()).collect::<Vec<_>>();
+    if bytes.len() < bytes_len {
+        return None;
+    }
+    bytes.truncate(bytes_len);
+    Some(bytes)
+}
+
+pub fn keccak256(bytes: &[u8]) -> [u8; 32] {
+    let mut keccak = Keccak::v256();
+    keccak.update(bytes);
+
+    let mut output = [0u8; 32];
+    keccak.finalize(&mut output);
+    output
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum MptError {
+    #[error("The proof has no nodes")]
+    EmptyProof,
+    #[error("The proof has {nodes} nodes but {lengths} node lengths")]
+    LengthMismatch { nodes: usize, lengths: usize },
+    #[error("The key has {0} nibbles, more than 64")]
+    KeyTooLong(usize),
+    #[error("Node {node_index} has fewer chunks than its {bytes_len} bytes")]
+    MissingChunks { node_index: usize, bytes_len: usize },
+    #[error("Node {0} does not hash to the expected value")]
+    HashMismatch(usize),
+    #[error("Node {node_index} reads past its end at byte {position}")]
+    OutOfBounds { node_index: usize, position: usize },
+    #[error("Node {node_index} has invalid list prefix {prefix:#x}")]
+    InvalidListPrefix { node_index: usize, prefix: u8 },
+    #[error("Node {node_index} has unsupported item prefix {prefix:#x} at byte {position}")]
+    UnsupportedItemPrefix { node_index: usize, prefix: u8, position: usize },
+    #[error("Node {0} has an empty path")]
+    EmptyPath(usize),
+    #[error("Node {0} encodes a path longer than the remaining key")]
+    PathExceedsKey(usize),
+    #[error("The key diverges from the path at node {0}, which is not the last node of the proof")]
+    EarlyDivergence(usize),
+    #[error("Node {node_index} references a child of {len} bytes, expected a 32-byte hash")]
+    InvalidChildLength { node_index: usize, len: usize },
+    #[error("Branch node {0} holds a child at the key nibble, the key is neither included nor excluded")]
+    NonEmptyBranchChild(usize),
+    #[error("Node {0} holds an empty value")]
+    EmptyValue(usize),
+    #[error("The proof checked {checked} nibbles of a {expected}-nibble key")]
+    KeyNotConsumed { checked: usize, expected: usize },
+    #[error("Node {node_index} is malformed: {source}")]
+    MalformedNode { node_index: usize, source: NodeError },
+}
+
+/// Failure of a storage proof, telling apart the account level from the storage level.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum StorageProofError {
+    #[error("Invalid account proof: {0}")]
+    Account(MptError),
+    #[error("The account is not an RLP list of nonce, balance, storage root and code hash")]
+    InvalidAccount,
+    #[error("Invalid storage proof: {0}")]
+    Storage(MptError),
+    #[error("The slot value is not an RLP string of at most 32 bytes")]
+    InvalidSlotValue,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum RlpError {
+    #[error("The RLP item at byte {0} ends past the end of its input")]
+    Truncated(usize),
+    #[error("The RLP item at byte {0} is not canonically encoded")]
+    NonCanonical(usize),
+    #[error("Unexpected bytes after the RLP item, from byte {0}")]
+    TrailingBytes(usize),
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum NodeError {
+    #[error(transparent)]
+    Rlp(#[from] RlpError),
+    #[error("Fewer chunks than the {0} bytes of the node")]
+    MissingChunks(usize),
+    #[error("Expected a list at byte {0}, found a string")]
+    NotAList(usize),
+    #[error("Expected a string at byte {0}, found a list")]
+    ExpectedString(usize),
+    #[error("The node at byte {position} has {count} items, expected 2 or 17")]
+    InvalidItemCount { position: usize, count: usize },
+    #[error("The path at byte {0} is empty")]
+    EmptyPath(usize),
+    #[error("The path at byte {position} has invalid hex-prefix flag {flag}")]
+    InvalidPathFlag { position: usize, flag: u8 },
+    #[error("The even path at byte {position} is padded with nibble {nibble:#x} instead of 0")]
+    InvalidPathPadding { position: usize, nibble: u8 },
+    #[error("The child at byte {position} is a {len}-byte string, expected empty or a 32-byte hash")]
+    InvalidChildLength { position: usize, len: usize },
+    #[error("The node embedded at byte {position} is {len} bytes, nodes of 32 bytes or more are referenced by hash")]
+    OversizedEmbeddedNode { position: usize, len: usize },
+    #[error("The extension at byte {0} has an empty child")]
+    EmptyExtensionChild(usize),
+    #[error("The leaf value at byte {0} is empty")]
+    EmptyValue(usize),
+}
+
+#[derive(Debug, Error)]
+pub enum GetProofError {
+    #[error("Failed to read the eth_getProof response: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid eth_getProof response: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+impl From<NodeError> for HintError {
+    fn from(error: NodeError) -> Self {
+        HintError::CustomHint(error.to_string().into_boxed_str())
+    }
+}
+
+impl From<StorageProofError> for HintError {
+    fn from(error: StorageProofError) -> Self {
+        HintError::CustomHint(error.to_string().into_boxed_str())
+    }
+}
+
+impl From<MptError> for HintError {
+    fn from(error: MptError) -> Self {
+        HintError::CustomHint(error.to_string().into_boxed_str())
+    }
+}
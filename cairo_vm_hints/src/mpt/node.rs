@@ -0,0 +1,174 @@
+use std::fmt;
+
+use super::{le_chunks_to_bytes, rlp, MptError, NodeError};
+
+/// A decoded Merkle Patricia Trie node, paths being given as nibbles.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+    Branch { children: Box<[Child; 16]>, value: Option<Vec<u8>> },
+    Extension { path: Vec<u8>, child: Child },
+    Leaf { path: Vec<u8>, value: Vec<u8> },
+}
+
+/// Reference from a node to one of its children.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Child {
+    Empty,
+    Hash([u8; 32]),
+    /// A child whose encoding is shorter than a hash, stored in its parent.
+    Embedded(Box<Node>),
+}
+
+impl Node {
+    pub fn decode(bytes: &[u8]) -> Result<Node, NodeError> {
+        decode_node(&rlp::decode(bytes)?)
+    }
+
+    /// Decodes a node given as in the inputs of `verify_mpt_proof`, as little-endian 8-byte chunks.
+    pub fn decode_le_chunks(chunks: &[u64], bytes_len: usize) -> Result<Node, NodeError> {
+        let bytes = le_chunks_to_bytes(chunks, bytes_len).ok_or(NodeError::MissingChunks(bytes_len))?;
+        Node::decode(&bytes)
+    }
+
+    /// Decodes all the nodes of a proof given as in the inputs of `verify_mpt_proof`.
+    pub fn decode_proof(mpt_proof: &[Vec<u64>], mpt_proof_bytes_len: &[usize]) -> Result<Vec<Node>, MptError> {
+        if mpt_proof.len() != mpt_proof_bytes_len.len() {
+            return Err(MptError::LengthMismatch {
+                nodes: mpt_proof.len(),
+                lengths: mpt_proof_bytes_len.len(),
+            });
+        }
+        mpt_proof
+            .iter()
+            .zip(mpt_proof_bytes_len)
+            .enumerate()
+            .map(|(node_index, (chunks, bytes_len))| {
+                Node::decode_le_chunks(chunks, *bytes_len).map_err(|source| MptError::MalformedNode { node_index, source })
+            })
+            .collect()
+    }
+}
+
+fn decode_node(item: &rlp::Item) -> Result<Node, NodeError> {
+    if !item.is_list {
+        return Err(NodeError::NotAList(item.start));
+    }
+    match item.items()?.as_slice() {
+        [path, second] => {
+            let (path, is_leaf) = decode_hex_prefix(path)?;
+            if is_leaf {
+                let value = string_payload(second)?;
+                if value.is_empty() {
+                    return Err(NodeError::EmptyValue(second.start));
+                }
+                Ok(Node::Leaf { path, value: value.to_vec() })
+            } else {
+                match decode_child(second)? {
+                    Child::Empty => Err(NodeError::EmptyExtensionChild(second.start)),
+                    child => Ok(Node::Extension { path, child }),
+                }
+            }
+        }
+        [children @ .., value] if children.len() == 16 => {
+            let children = children.iter().map(decode_child).collect::<Result<Vec<_>, _>>()?;
+            let value = string_payload(value)?;
+            Ok(Node::Branch {
+                children: Box::new(children.try_into().unwrap()),
+                value: (!value.is_empty()).then(|| value.to_vec()),
+            })
+        }
+        items => Err(NodeError::InvalidItemCount {
+            position: item.start,
+            count: items.len(),
+        }),
+    }
+}
+
+fn decode_child(item: &rlp::Item) -> Result<Child, NodeError> {
+    if item.is_list {
+        let len = item.encoded().len();
+        if len >= 32 {
+            return Err(NodeError::OversizedEmbeddedNode { position: item.start, len });
+        }
+        return Ok(Child::Embedded(Box::new(decode_node(item)?)));
+    }
+    match item.payload() {
+        [] => Ok(Child::Empty),
+        hash if hash.len() == 32 => Ok(Child::Hash(hash.try_into().unwrap())),
+        payload => Err(NodeError::InvalidChildLength {
+            position: item.start,
+            len: payload.len(),
+        }),
+    }
+}
+
+/// Decodes a hex-prefix encoded path into its nibbles, along with whether it is the path of a leaf.
+fn decode_hex_prefix(item: &rlp::Item) -> Result<(Vec<u8>, bool), NodeError> {
+    let encoded = string_payload(item)?;
+    let Some(first) = encoded.first() else {
+        return Err(NodeError::EmptyPath(item.start));
+    };
+    let (is_leaf, odd) = match first >> 4 {
+        0 => (false, false),
+        1 => (false, true),
+        2 => (true, false),
+        3 => (true, true),
+        flag => return Err(NodeError::InvalidPathFlag { position: item.start, flag }),
+    };
+    if !odd && first & 0xf != 0 {
+        return Err(NodeError::InvalidPathPadding {
+            position: item.start,
+            nibble: first & 0xf,
+        });
+    }
+    let path = encoded
+        .iter()
+        .flat_map(|byte| [byte >> 4, byte & 0xf])
+        .skip(if odd { 1 } else { 2 })
+        .collect();
+    Ok((path, is_leaf))
+}
+
+fn string_payload<'a>(item: &rlp::Item<'a>) -> Result<&'a [u8], NodeError> {
+    if item.is_list {
+        return Err(NodeError::ExpectedString(item.start));
+    }
+    Ok(item.payload())
+}
+
+impl fmt::Display for Node {
+    /// Formats the node on one line, paths as nibbles and byte strings in hex.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Node::Branch { children, value } => {
+                write!(f, "branch {{")?;
+                let mut separator = "";
+                for (nibble, child) in children.iter().enumerate().filter(|(_, child)| **child != Child::Empty) {
+                    write!(f, "{separator}{nibble:x}: {child}")?;
+                    separator = ", ";
+                }
+                write!(f, "}}")?;
+                match value {
+                    Some(value) => write!(f, " value=0x{}", hex::encode(value)),
+                    None => Ok(()),
+                }
+            }
+            Node::Extension { path, child } => write!(f, "extension path={} child={child}", nibbles_to_string(path)),
+            Node::Leaf { path, value } => write!(f, "leaf path={} value=0x{}", nibbles_to_string(path), hex::encode(value)),
+        }
+    }
+}
+
+impl fmt::Display for Child {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Child::Empty => write!(f, "empty"),
+            Child::Hash(hash) => write!(f, "0x{}", hex::encode(hash)),
+            Child::Embedded(node) => write!(f, "({node})"),
+        }
+    }
+}
+
+fn nibbles_to_string(nibbles: &[u8]) -> String {
+    nibbles.iter().map(|nibble| format!("{nibble:x}")).collect()
+}
@@ -0,0 +1,33 @@
+use super::{bytes_to_le_chunks, keccak256, rlp, verify_mpt_proof, MptError, Uint256};
+
+/// Root of the trie with no entries, the hash of an empty string.
+pub fn empty_root() -> [u8; 32] {
+    keccak256(&rlp::encode_bytes(&[]))
+}
+
+/// Verifies a proof given as raw nodes from `root` down to `key` with `verify_mpt_proof`, and thus with
+/// the semantics of `lib/mpt.cairo`. Returns the value at `key`, or `None` if the proof shows that the
+/// key is not in the trie.
+pub fn verify_proof(root: [u8; 32], key: &[u8], proof: &[Vec<u8>]) -> Result<Option<Vec<u8>>, MptError> {
+    // The empty trie is proven by no nodes, or by its single empty root node.
+    if root == empty_root() && (proof.is_empty() || proof == [rlp::encode_bytes(&[])]) {
+        return Ok(None);
+    }
+
+    let mut key_be = [0u8; 32];
+    let start = 32usize.checked_sub(key.len()).ok_or(MptError::KeyTooLong(2 * key.len()))?;
+    key_be[start..].copy_from_slice(key);
+    let leading_zeroes_nibbles = match key.iter().position(|byte| *byte != 0) {
+        Some(position) => 2 * position + (key[position] < 0x10) as usize,
+        None => 2 * key.len(),
+    };
+
+    let value = verify_mpt_proof(
+        &proof.iter().map(|node| bytes_to_le_chunks(node)).collect::<Vec<_>>(),
+        &proof.iter().map(Vec::len).collect::<Vec<_>>(),
+        Uint256::from_be_bytes(key_be),
+        leading_zeroes_nibbles,
+        Uint256::from_le_bytes(root),
+    )?;
+    Ok(value.bytes())
+}
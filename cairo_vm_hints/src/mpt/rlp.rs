@@ -0,0 +1,123 @@
+//! Minimal RLP encoding and decoding, enough to build and read trie nodes and test fixtures.
+
+use super::RlpError;
+
+pub fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+    if let [byte @ 0x00..=0x7f] = bytes {
+        return vec![*byte];
+    }
+    [encode_length(0x80, bytes.len()), bytes.to_vec()].concat()
+}
+
+/// Encodes a list of already encoded items.
+pub fn encode_list(items: &[Vec<u8>]) -> Vec<u8> {
+    let payload = items.concat();
+    [encode_length(0xc0, payload.len()), payload].concat()
+}
+
+/// Encodes an unsigned integer as its minimal big-endian representation, zero being the empty string.
+pub fn encode_uint(value: u64) -> Vec<u8> {
+    encode_bytes(trim_leading_zeroes(&value.to_be_bytes()))
+}
+
+pub fn trim_leading_zeroes(bytes: &[u8]) -> &[u8] {
+    let start = bytes.iter().position(|byte| *byte != 0).unwrap_or(bytes.len());
+    &bytes[start..]
+}
+
+fn encode_length(offset: u8, len: usize) -> Vec<u8> {
+    if len <= 55 {
+        return vec![offset + len as u8];
+    }
+    let len_bytes = trim_leading_zeroes(&len.to_be_bytes()).to_vec();
+    [vec![offset + 55 + len_bytes.len() as u8], len_bytes].concat()
+}
+
+/// An item of an RLP encoded buffer, located by its byte offsets so that errors can point into the buffer.
+#[derive(Debug, Clone, Copy)]
+pub struct Item<'a> {
+    bytes: &'a [u8],
+    pub start: usize,
+    pub payload_start: usize,
+    pub end: usize,
+    pub is_list: bool,
+}
+
+impl<'a> Item<'a> {
+    /// The string, or the encoded items of the list.
+    pub fn payload(&self) -> &'a [u8] {
+        &self.bytes[self.payload_start..self.end]
+    }
+
+    /// The item along with its prefix.
+    pub fn encoded(&self) -> &'a [u8] {
+        &self.bytes[self.start..self.end]
+    }
+
+    /// The items of a list, an empty vector for a string.
+    pub fn items(&self) -> Result<Vec<Item<'a>>, RlpError> {
+        let mut items = vec![];
+        if !self.is_list {
+            return Ok(items);
+        }
+        let mut position = self.payload_start;
+        while position < self.end {
+            let item = decode_item_at(&self.bytes[..self.end], position)?;
+            position = item.end;
+            items.push(item);
+        }
+        Ok(items)
+    }
+}
+
+/// Decodes `bytes` as a single RLP item.
+pub fn decode(bytes: &[u8]) -> Result<Item<'_>, RlpError> {
+    let item = decode_item_at(bytes, 0)?;
+    if item.end != bytes.len() {
+        return Err(RlpError::TrailingBytes(item.end));
+    }
+    Ok(item)
+}
+
+/// Decodes the item starting at `start`, rejecting non-canonical encodings.
+fn decode_item_at(bytes: &[u8], start: usize) -> Result<Item<'_>, RlpError> {
+    let prefix = *bytes.get(start).ok_or(RlpError::Truncated(start))?;
+    let (payload_start, len, is_list) = match prefix {
+        0x00..=0x7f => (start, 1, false),
+        0x80..=0xb7 => (start + 1, (prefix - 0x80) as usize, false),
+        0xb8..=0xbf => decode_long_length(bytes, start, (prefix - 0xb7) as usize, false)?,
+        0xc0..=0xf7 => (start + 1, (prefix - 0xc0) as usize, true),
+        0xf8..=0xff => decode_long_length(bytes, start, (prefix - 0xf7) as usize, true)?,
+    };
+    let end = payload_start
+        .checked_add(len)
+        .filter(|end| *end <= bytes.len())
+        .ok_or(RlpError::Truncated(start))?;
+    // A single byte below 0x80 is its own encoding.
+    if prefix == 0x81 && bytes[payload_start] < 0x80 {
+        return Err(RlpError::NonCanonical(start));
+    }
+    Ok(Item {
+        bytes,
+        start,
+        payload_start,
+        end,
+        is_list,
+    })
+}
+
+fn decode_long_length(bytes: &[u8], start: usize, len_len: usize, is_list: bool) -> Result<(usize, usize, bool), RlpError> {
+    let len_bytes = bytes.get(start + 1..start + 1 + len_len).ok_or(RlpError::Truncated(start))?;
+    // Lengths are minimal big-endian numbers, and only used past 55 bytes.
+    if len_bytes[0] == 0 {
+        return Err(RlpError::NonCanonical(start));
+    }
+    let len = len_bytes
+        .iter()
+        .try_fold(0usize, |len, byte| len.checked_mul(256).map(|len| len | *byte as usize))
+        .ok_or(RlpError::Truncated(start))?;
+    if len <= 55 {
+        return Err(RlpError::NonCanonical(start));
+    }
+    Ok((start + 1 + len_len, len, is_list))
+}
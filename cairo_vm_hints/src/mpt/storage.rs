@@ -0,0 +1,75 @@
+use super::proof::{empty_root, verify_proof};
+use super::{hashed_key, rlp, StorageProofError, Uint256};
+
+/// An account of the state trie, decoded from its RLP encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Account {
+    pub nonce: u64,
+    pub balance: Uint256,
+    pub storage_root: [u8; 32],
+    pub code_hash: [u8; 32],
+}
+
+impl Account {
+    /// Decodes the list of nonce, balance, storage root and code hash stored in the state trie.
+    pub fn decode(bytes: &[u8]) -> Option<Account> {
+        let item = rlp::decode(bytes).ok()?;
+        let items = item.items().ok()?;
+        let [nonce, balance, storage_root, code_hash] = items.as_slice() else {
+            return None;
+        };
+        if !item.is_list || items.iter().any(|item| item.is_list) || nonce.payload().len() > 8 {
+            return None;
+        }
+        Some(Account {
+            nonce: nonce.payload().iter().fold(0, |nonce, byte| nonce << 8 | *byte as u64),
+            balance: Uint256::from_be_bytes(be_word(balance.payload())?),
+            storage_root: storage_root.payload().try_into().ok()?,
+            code_hash: code_hash.payload().try_into().ok()?,
+        })
+    }
+}
+
+/// Verifies the proof of `address` against `state_root`. Returns the account, or `None` if the proof
+/// shows that the account does not exist.
+pub fn verify_account_proof(state_root: [u8; 32], address: &[u8], account_proof: &[Vec<u8>]) -> Result<Option<Account>, StorageProofError> {
+    match verify_proof(state_root, &hashed_key(address), account_proof).map_err(StorageProofError::Account)? {
+        Some(account) => Ok(Some(Account::decode(&account).ok_or(StorageProofError::InvalidAccount)?)),
+        None => Ok(None),
+    }
+}
+
+/// Verifies the value of `slot` in the storage of `address`: the account proof against `state_root`,
+/// then the storage proof against the storage root of the account. Slots missing from the storage,
+/// or from the storage of a missing account, hold zero.
+pub fn verify_storage_proof(
+    state_root: [u8; 32],
+    address: &[u8],
+    account_proof: &[Vec<u8>],
+    slot: [u8; 32],
+    storage_proof: &[Vec<u8>],
+) -> Result<Uint256, StorageProofError> {
+    let storage_root = match verify_account_proof(state_root, address, account_proof)? {
+        Some(account) => account.storage_root,
+        None => empty_root(),
+    };
+    match verify_proof(storage_root, &hashed_key(&slot), storage_proof).map_err(StorageProofError::Storage)? {
+        Some(value) => {
+            let item = rlp::decode(&value).map_err(|_| StorageProofError::InvalidSlotValue)?;
+            if item.is_list {
+                return Err(StorageProofError::InvalidSlotValue);
+            }
+            Ok(Uint256::from_be_bytes(
+                be_word(item.payload()).ok_or(StorageProofError::InvalidSlotValue)?,
+            ))
+        }
+        None => Ok(Uint256::default()),
+    }
+}
+
+/// Left-pads a big-endian number of at most 32 bytes.
+fn be_word(bytes: &[u8]) -> Option<[u8; 32]> {
+    let mut word = [0u8; 32];
+    word.get_mut(32usize.checked_sub(bytes.len())?..)?.copy_from_slice(bytes);
+    Some(word)
+}
@@ -0,0 +1,214 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::{keccak256, rlp};
+
+/// Trie a proof is made against, serialized as in the fixtures of `tests/rust`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProofKind {
+    #[serde(rename = "tx_proof")]
+    TxProof,
+    #[serde(rename = "receipt_proof")]
+    ReceiptProof,
+    #[serde(rename = "account_proof")]
+    AccountProof,
+    #[serde(rename = "storage_proof")]
+    StorageProof,
+}
+
+/// A proof in the JSON shape of `MptProof` in `tests/rust`, byte strings being hex-encoded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MptProof {
+    #[serde(with = "hex::serde")]
+    pub root: [u8; 32],
+    #[serde(with = "hex_list")]
+    pub proof: Vec<Vec<u8>>,
+    #[serde(with = "hex::serde")]
+    pub key: Vec<u8>,
+    pub kind: ProofKind,
+}
+
+/// Key of the `index`-th item of the transaction and receipt tries.
+pub fn key_from_index(index: u64) -> Vec<u8> {
+    rlp::encode_uint(index)
+}
+
+/// Key of an account in the state trie, or of a slot in a storage trie.
+pub fn hashed_key(address_or_slot: &[u8]) -> Vec<u8> {
+    keccak256(address_or_slot).to_vec()
+}
+
+/// In-memory Merkle Patricia Trie built from key/value pairs, producing the same roots and
+/// proofs as the Ethereum tries.
+#[derive(Debug, Clone, Default)]
+pub struct Trie {
+    entries: BTreeMap<Vec<u8>, Vec<u8>>,
+}
+
+impl Trie {
+    pub fn new() -> Trie {
+        Trie::default()
+    }
+
+    /// Sets the value at `key`, an empty value removing the key as in the Ethereum tries.
+    pub fn insert(&mut self, key: &[u8], value: Vec<u8>) {
+        if value.is_empty() {
+            self.entries.remove(key);
+        } else {
+            self.entries.insert(key.to_vec(), value);
+        }
+    }
+
+    pub fn get(&self, key: &[u8]) -> Option<&[u8]> {
+        self.entries.get(key).map(Vec::as_slice)
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        match self.leaves().as_slice() {
+            [] => keccak256(&rlp::encode_bytes(&[])),
+            leaves => keccak256(&encode_node(leaves, 0)),
+        }
+    }
+
+    /// Nodes from the root down to `key`, or down to where the path to `key` ends if it is not in
+    /// the trie. Nodes embedded in their parent are not repeated.
+    pub fn get_proof(&self, key: &[u8]) -> Vec<Vec<u8>> {
+        let leaves = self.leaves();
+        let mut proof = vec![];
+        if !leaves.is_empty() {
+            collect_proof(&leaves, 0, &to_nibbles(key), true, &mut proof);
+        }
+        proof
+    }
+
+    pub fn get_mpt_proof(&self, key: &[u8], kind: ProofKind) -> MptProof {
+        MptProof {
+            root: self.root(),
+            proof: self.get_proof(key),
+            key: key.to_vec(),
+            kind,
+        }
+    }
+
+    /// Keys as nibbles along with their values, sorted by key.
+    fn leaves(&self) -> Vec<(Vec<u8>, &[u8])> {
+        self.entries.iter().map(|(key, value)| (to_nibbles(key), value.as_slice())).collect()
+    }
+}
+
+/// Shape of the node holding `leaves`, all of which share their first `depth` nibbles.
+enum Shape {
+    Leaf,
+    Extension(usize),
+    Branch,
+}
+
+fn shape(leaves: &[(Vec<u8>, &[u8])], depth: usize) -> Shape {
+    if leaves.len() == 1 {
+        return Shape::Leaf;
+    }
+    let (first, last) = (&leaves[0].0, &leaves[leaves.len() - 1].0);
+    // Keys being sorted, the first and last ones share the prefix common to all of them.
+    match first[depth..].iter().zip(&last[depth..]).take_while(|(a, b)| a == b).count() {
+        0 => Shape::Branch,
+        prefix_len => Shape::Extension(prefix_len),
+    }
+}
+
+fn encode_node(leaves: &[(Vec<u8>, &[u8])], depth: usize) -> Vec<u8> {
+    match shape(leaves, depth) {
+        Shape::Leaf => {
+            let (key, value) = &leaves[0];
+            rlp::encode_list(&[rlp::encode_bytes(&hex_prefix(&key[depth..], true)), rlp::encode_bytes(value)])
+        }
+        Shape::Extension(prefix_len) => {
+            let path = &leaves[0].0[depth..depth + prefix_len];
+            rlp::encode_list(&[
+                rlp::encode_bytes(&hex_prefix(path, false)),
+                node_reference(encode_node(leaves, depth + prefix_len)),
+            ])
+        }
+        Shape::Branch => {
+            let mut items = (0..16)
+                .map(|nibble| match branch_children(leaves, depth, nibble) {
+                    [] => rlp::encode_bytes(&[]),
+                    children => node_reference(encode_node(children, depth + 1)),
+                })
+                .collect::<Vec<_>>();
+            items.push(match leaves.iter().find(|(key, _)| key.len() == depth) {
+                Some((_, value)) => rlp::encode_bytes(value),
+                None => rlp::encode_bytes(&[]),
+            });
+            rlp::encode_list(&items)
+        }
+    }
+}
+
+fn collect_proof(leaves: &[(Vec<u8>, &[u8])], depth: usize, key: &[u8], is_root: bool, proof: &mut Vec<Vec<u8>>) {
+    let encoded = encode_node(leaves, depth);
+    if is_root || encoded.len() >= 32 {
+        proof.push(encoded);
+    }
+
+    match shape(leaves, depth) {
+        Shape::Leaf => {}
+        Shape::Extension(prefix_len) => {
+            if key.get(depth..depth + prefix_len) == Some(&leaves[0].0[depth..depth + prefix_len]) {
+                collect_proof(leaves, depth + prefix_len, key, false, proof);
+            }
+        }
+        Shape::Branch => {
+            if let Some(nibble) = key.get(depth) {
+                let children = branch_children(leaves, depth, *nibble);
+                if !children.is_empty() {
+                    collect_proof(children, depth + 1, key, false, proof);
+                }
+            }
+        }
+    }
+}
+
+/// The leaves below the child at `nibble` of a branch at `depth`, contiguous since keys are sorted.
+fn branch_children<'a, 'b>(leaves: &'a [(Vec<u8>, &'b [u8])], depth: usize, nibble: u8) -> &'a [(Vec<u8>, &'b [u8])] {
+    let start = leaves.partition_point(|(key, _)| key.get(depth) < Some(&nibble));
+    let end = leaves.partition_point(|(key, _)| key.get(depth) <= Some(&nibble));
+    &leaves[start..end]
+}
+
+/// Nodes shorter than a hash are embedded in their parent instead of being referenced by hash.
+fn node_reference(encoded: Vec<u8>) -> Vec<u8> {
+    if encoded.len() < 32 {
+        encoded
+    } else {
+        rlp::encode_bytes(&keccak256(&encoded))
+    }
+}
+
+fn to_nibbles(bytes: &[u8]) -> Vec<u8> {
+    bytes.iter().flat_map(|byte| [byte >> 4, byte & 0xf]).collect()
+}
+
+/// Hex-prefix encoding of a path, flagging leaves and odd lengths in the first nibble.
+fn hex_prefix(nibbles: &[u8], leaf: bool) -> Vec<u8> {
+    let flag = 2 * leaf as u8 + (nibbles.len() % 2) as u8;
+    let padded = match nibbles.len() % 2 {
+        0 => [&[flag, 0][..], nibbles].concat(),
+        _ => [&[flag][..], nibbles].concat(),
+    };
+    padded.chunks(2).map(|pair| pair[0] << 4 | pair[1]).collect()
+}
+
+mod hex_list {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(items: &[Vec<u8>], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(items.iter().map(hex::encode))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Vec<u8>>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|item| hex::decode(item).map_err(D::Error::custom))
+            .collect()
+    }
+}
@@ -0,0 +1,324 @@
+use super::{bytes_to_le_chunks, keccak256, le_chunks_to_bytes, MptError, Uint256};
+
+/// Result of `verify_mpt_proof`, laid out as the values returned by the Cairo function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MptValue {
+    /// The value as little-endian 8-byte chunks, or the last proof node for a non-inclusion proof.
+    pub value: Vec<u64>,
+    /// Length of the value in bytes, `-1` for a non-inclusion proof.
+    pub value_len: i64,
+}
+
+impl MptValue {
+    pub fn is_included(&self) -> bool {
+        self.value_len >= 0
+    }
+
+    /// The value bytes, `None` for a non-inclusion proof.
+    pub fn bytes(&self) -> Option<Vec<u8>> {
+        le_chunks_to_bytes(&self.value, usize::try_from(self.value_len).ok()?)
+    }
+}
+
+/// Verifies a Merkle Patricia Tree proof with the inputs and semantics of `lib/mpt.cairo::verify_mpt_proof`:
+/// - `mpt_proof`: the proof nodes, each as little-endian 8-byte chunks.
+/// - `mpt_proof_bytes_len`: the length in bytes of each node.
+/// - `key_be`: the key as a big-endian number.
+/// - `key_be_leading_zeroes_nibbles`: the number of leading zero nibbles of the key, 3 for `0x007`.
+/// - `root`: the root of the trie as a little-endian number.
+///
+/// Every input the Cairo program would fail on is reported as an error, including an empty value,
+/// which `extract_n_bytes_from_le_64_chunks_array` cannot return. Otherwise the value is returned,
+/// or the last node with `value_len = -1` if the proof shows that the key is not in the trie.
+pub fn verify_mpt_proof(
+    mpt_proof: &[Vec<u64>],
+    mpt_proof_bytes_len: &[usize],
+    key_be: Uint256,
+    key_be_leading_zeroes_nibbles: usize,
+    root: Uint256,
+) -> Result<MptValue, MptError> {
+    if mpt_proof.len() != mpt_proof_bytes_len.len() {
+        return Err(MptError::LengthMismatch {
+            nodes: mpt_proof.len(),
+            lengths: mpt_proof_bytes_len.len(),
+        });
+    }
+    let last_index = mpt_proof.len().checked_sub(1).ok_or(MptError::EmptyProof)?;
+    let key = key_nibbles(key_be, key_be_leading_zeroes_nibbles)?;
+
+    let mut hash_to_assert = root.to_le_bytes();
+    let mut n_nibbles_checked = 0;
+    for node_index in 0..last_index {
+        let bytes = read_node(&mpt_proof[node_index], mpt_proof_bytes_len[node_index], node_index, &hash_to_assert)?;
+        let node = Node {
+            index: node_index,
+            bytes: &bytes,
+        };
+        match resolve_child(&node, &key, n_nibbles_checked)? {
+            Some((hash, nibbles_checked)) => {
+                hash_to_assert = hash;
+                n_nibbles_checked = nibbles_checked;
+            }
+            None => return Err(MptError::EarlyDivergence(node_index)),
+        }
+    }
+
+    let bytes = read_node(&mpt_proof[last_index], mpt_proof_bytes_len[last_index], last_index, &hash_to_assert)?;
+    let node = Node {
+        index: last_index,
+        bytes: &bytes,
+    };
+    match resolve_value(&node, &key, n_nibbles_checked)? {
+        Some((value, nibbles_checked)) => {
+            if nibbles_checked != key.len() {
+                return Err(MptError::KeyNotConsumed {
+                    checked: nibbles_checked,
+                    expected: key.len(),
+                });
+            }
+            Ok(MptValue {
+                value: bytes_to_le_chunks(&value),
+                value_len: value.len() as i64,
+            })
+        }
+        None => Ok(MptValue {
+            value: mpt_proof[last_index].clone(),
+            value_len: -1,
+        }),
+    }
+}
+
+/// Nibbles of the key from the most significant one, leading zeroes included.
+fn key_nibbles(key_be: Uint256, leading_zeroes_nibbles: usize) -> Result<Vec<u8>, MptError> {
+    let nibbles = key_be
+        .to_be_bytes()
+        .into_iter()
+        .flat_map(|byte| [byte >> 4, byte & 0xf])
+        .skip_while(|nibble| *nibble == 0)
+        .collect::<Vec<_>>();
+    if leading_zeroes_nibbles + nibbles.len() > 64 {
+        return Err(MptError::KeyTooLong(leading_zeroes_nibbles + nibbles.len()));
+    }
+    Ok([vec![0; leading_zeroes_nibbles], nibbles].concat())
+}
+
+fn read_node(chunks: &[u64], bytes_len: usize, node_index: usize, expected_hash: &[u8; 32]) -> Result<Vec<u8>, MptError> {
+    let bytes = le_chunks_to_bytes(chunks, bytes_len).ok_or(MptError::MissingChunks { node_index, bytes_len })?;
+    if keccak256(&bytes) != *expected_hash {
+        return Err(MptError::HashMismatch(node_index));
+    }
+    Ok(bytes)
+}
+
+struct Node<'a> {
+    index: usize,
+    bytes: &'a [u8],
+}
+
+impl Node<'_> {
+    fn byte(&self, position: usize) -> Result<u8, MptError> {
+        self.bytes.get(position).copied().ok_or_else(|| self.out_of_bounds(position))
+    }
+
+    fn slice(&self, start: usize, len: usize) -> Result<&[u8], MptError> {
+        self.bytes.get(start..start + len).ok_or_else(|| self.out_of_bounds(start + len))
+    }
+
+    fn out_of_bounds(&self, position: usize) -> MptError {
+        MptError::OutOfBounds {
+            node_index: self.index,
+            position,
+        }
+    }
+
+    fn unsupported_prefix(&self, prefix: u8, position: usize) -> MptError {
+        MptError::UnsupportedItemPrefix {
+            node_index: self.index,
+            prefix,
+            position,
+        }
+    }
+}
+
+/// Byte offsets of the first items of a node, as decoded by `decode_node_list_lazy`.
+struct Layout {
+    first_item_start: usize,
+    first_item_prefix: u8,
+    first_item_is_string: bool,
+    first_item_len: usize,
+    second_item_value_start: usize,
+    second_item_len: usize,
+    third_item_start: usize,
+}
+
+impl Layout {
+    fn parse(node: &Node) -> Result<Layout, MptError> {
+        let first_item_start = match node.byte(0)? {
+            0xc0..=0xf7 => 1,
+            // The first item must start in the first 8-byte chunk, which rules out 0xfe and 0xff.
+            prefix @ 0xf8..=0xfd => 1 + (prefix - 0xf7) as usize,
+            prefix => {
+                return Err(MptError::InvalidListPrefix {
+                    node_index: node.index,
+                    prefix,
+                })
+            }
+        };
+
+        let first_item_prefix = node.byte(first_item_start)?;
+        let (first_item_is_string, first_item_len) = match first_item_prefix {
+            0x00..=0x7f => (false, 1),
+            0x80..=0xb7 => (true, (first_item_prefix - 0x80) as usize),
+            prefix => return Err(node.unsupported_prefix(prefix, first_item_start)),
+        };
+        let second_item_start = first_item_start + first_item_is_string as usize + first_item_len;
+
+        let (second_item_value_start, second_item_len) = match node.byte(second_item_start)? {
+            0x00..=0x7f => (second_item_start, 1),
+            prefix @ 0x80..=0xb7 => (second_item_start + 1, (prefix - 0x80) as usize),
+            prefix @ 0xb8..=0xbf => {
+                let len_len = (prefix - 0xb7) as usize;
+                let value_start = second_item_start + 1 + len_len;
+                // A length that overflows the offsets of the node can only end past its end.
+                let len = node
+                    .slice(second_item_start + 1, len_len)?
+                    .iter()
+                    .try_fold(0usize, |len, byte| len.checked_mul(256).map(|len| len | *byte as usize))
+                    .filter(|len| value_start.checked_add(*len).is_some())
+                    .ok_or(MptError::OutOfBounds {
+                        node_index: node.index,
+                        position: second_item_start,
+                    })?;
+                (value_start, len)
+            }
+            prefix => return Err(node.unsupported_prefix(prefix, second_item_start)),
+        };
+
+        Ok(Layout {
+            first_item_start,
+            first_item_prefix,
+            first_item_is_string,
+            first_item_len,
+            second_item_value_start,
+            second_item_len,
+            third_item_start: second_item_value_start + second_item_len,
+        })
+    }
+
+    /// Two-item nodes are leaves or extensions, any other node is a branch.
+    fn is_leaf_or_extension(&self, node: &Node) -> bool {
+        self.third_item_start == node.bytes.len()
+    }
+}
+
+/// Resolves a node that is not the last of the proof to the hash of the next node, along with the
+/// updated number of checked nibbles. `None` if the key diverges from the path of a leaf or extension.
+fn resolve_child(node: &Node, key: &[u8], n_nibbles_checked: usize) -> Result<Option<([u8; 32], usize)>, MptError> {
+    let layout = Layout::parse(node)?;
+    if layout.is_leaf_or_extension(node) {
+        let Some(n_nibbles_checked) = match_path(node, &layout, key, n_nibbles_checked)? else {
+            return Ok(None);
+        };
+        if layout.second_item_len != 32 {
+            return Err(MptError::InvalidChildLength {
+                node_index: node.index,
+                len: layout.second_item_len,
+            });
+        }
+        let hash = node.slice(layout.second_item_value_start, 32)?;
+        Ok(Some((hash.try_into().unwrap(), n_nibbles_checked)))
+    } else {
+        let nibble = *key.get(n_nibbles_checked).ok_or(MptError::PathExceedsKey(node.index))?;
+        let position = branch_child_position(node, &layout, nibble)?;
+        let hash = node.slice(position, 32)?;
+        Ok(Some((hash.try_into().unwrap(), n_nibbles_checked + 1)))
+    }
+}
+
+/// Resolves the last node of the proof to the value and the total number of checked nibbles.
+/// `None` for a non-inclusion proof.
+fn resolve_value(node: &Node, key: &[u8], n_nibbles_checked: usize) -> Result<Option<(Vec<u8>, usize)>, MptError> {
+    let layout = Layout::parse(node)?;
+    if layout.is_leaf_or_extension(node) {
+        let Some(n_nibbles_checked) = match_path(node, &layout, key, n_nibbles_checked)? else {
+            return Ok(None);
+        };
+        if layout.second_item_len == 0 {
+            return Err(MptError::EmptyValue(node.index));
+        }
+        let value = node.slice(layout.second_item_value_start, layout.second_item_len)?;
+        Ok(Some((value.to_vec(), n_nibbles_checked)))
+    } else if n_nibbles_checked != key.len() {
+        // The key continues below the branch, the child it points to must be empty. Like the Cairo
+        // implementation, this reads the byte following the prefix of the child, which for an empty
+        // child is the prefix of the next item.
+        let position = branch_child_position(node, &layout, key[n_nibbles_checked])?;
+        if node.byte(position)? != 0x80 {
+            return Err(MptError::NonEmptyBranchChild(node.index));
+        }
+        Ok(None)
+    } else {
+        // The key ends at the branch, the value is its 17th item, returned with its RLP prefix.
+        let value_start = jump_to_item(node, 2, 16, layout.third_item_start)?;
+        let value = node.bytes.get(value_start..).ok_or_else(|| node.out_of_bounds(value_start))?;
+        if value.is_empty() {
+            return Err(MptError::EmptyValue(node.index));
+        }
+        Ok(Some((value.to_vec(), n_nibbles_checked)))
+    }
+}
+
+/// Checks the hex-prefix encoded path of a leaf or extension against the key, returning the
+/// updated number of checked nibbles if it matches.
+fn match_path(node: &Node, layout: &Layout, key: &[u8], n_nibbles_checked: usize) -> Result<Option<usize>, MptError> {
+    let flag = node.byte(layout.first_item_start + layout.first_item_is_string as usize)? >> 4;
+    // As in the Cairo implementation, any flag other than 0 (even extension) and 2 (even leaf) is odd.
+    let odd = !matches!(flag, 0 | 2);
+
+    if !layout.first_item_is_string {
+        // The path fits in the single byte of the item, holding at most one nibble.
+        if !odd {
+            return Ok(Some(n_nibbles_checked));
+        }
+        let nibble = *key.get(n_nibbles_checked).ok_or(MptError::PathExceedsKey(node.index))?;
+        return Ok((nibble == layout.first_item_prefix & 0xf).then_some(n_nibbles_checked + 1));
+    }
+
+    if layout.first_item_len == 0 {
+        return Err(MptError::EmptyPath(node.index));
+    }
+    let path = node
+        .slice(layout.first_item_start + 1, layout.first_item_len)?
+        .iter()
+        .flat_map(|byte| [byte >> 4, byte & 0xf])
+        .skip(if odd { 1 } else { 2 })
+        .collect::<Vec<_>>();
+    let key_path = key
+        .get(n_nibbles_checked..n_nibbles_checked + path.len())
+        .ok_or(MptError::PathExceedsKey(node.index))?;
+    Ok((key_path == path).then_some(n_nibbles_checked + path.len()))
+}
+
+/// Position of the byte following the prefix of the branch child at `nibble`, as computed by
+/// `get_branch_value_precomputed_offsets_1_2_3`. For a 32-byte child, this is where its hash starts.
+fn branch_child_position(node: &Node, layout: &Layout, nibble: u8) -> Result<usize, MptError> {
+    Ok(match nibble {
+        0 => layout.first_item_start + 1,
+        1 => layout.second_item_value_start,
+        2 => layout.third_item_start + 1,
+        _ => jump_to_item(node, 2, nibble as usize, layout.third_item_start)? + 1,
+    })
+}
+
+/// Skips the items of a branch from the one at `index`, starting at `position`, to the item at `target_index`.
+fn jump_to_item(node: &Node, mut index: usize, target_index: usize, mut position: usize) -> Result<usize, MptError> {
+    while index < target_index {
+        position += match node.byte(position)? {
+            0x00..=0x7f => 1,
+            prefix @ 0x80..=0xb7 => 1 + (prefix - 0x80) as usize,
+            prefix => return Err(node.unsupported_prefix(prefix, position)),
+        };
+        index += 1;
+    }
+    Ok(position)
+}
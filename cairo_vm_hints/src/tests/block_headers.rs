@@ -0,0 +1,49 @@
+use super::run_cairo_program_with_input;
+use crate::hint_processor::ProgramInput;
+use serde_json::{json, Value};
+
+const PROGRAM: &[u8] = include_bytes!("../../../build/compiled_cairo_files/read_block_headers.json");
+
+/// The output of `read_block_headers.cairo`, or the error of its run.
+fn read_block_headers(program_input: Value) -> Result<Vec<String>, String> {
+    let mut runner = run_cairo_program_with_input(PROGRAM, ProgramInput::new(program_input)).map_err(|error| error.to_string())?;
+    let mut output = String::new();
+    runner.vm.write_output(&mut output).unwrap();
+    Ok(output.lines().map(str::to_string).collect())
+}
+
+#[test]
+fn test_read_block_headers() {
+    let output = read_block_headers(json!({
+        "block_headers_array": [[1, "0x2"], ["18446744073709551615"]],
+        "bytes_len_array": [9, "0x8"],
+    }));
+    assert_eq!(output.unwrap(), ["9", "1", "2", "8", "18446744073709551615"]);
+}
+
+#[test]
+fn test_missing_program_input() {
+    let error = read_block_headers(json!({ "block_headers_array": [[1]] })).unwrap_err();
+    assert!(error.contains("Missing program input: bytes_len_array"), "{error}");
+}
+
+#[test]
+fn test_malformed_block_headers() {
+    for block_headers_array in [json!([1, 2]), json!([[1], ["1x"]]), json!({ "0": [1] })] {
+        let error = read_block_headers(json!({
+            "block_headers_array": block_headers_array,
+            "bytes_len_array": [8, 8],
+        }))
+        .unwrap_err();
+        assert!(
+            error.contains("Program input block_headers_array is not an array of felt arrays"),
+            "{error}"
+        );
+    }
+    let error = read_block_headers(json!({
+        "block_headers_array": [[1], [2]],
+        "bytes_len_array": [[8], 8],
+    }))
+    .unwrap_err();
+    assert!(error.contains("Program input bytes_len_array is not a felt array"), "{error}");
+}
@@ -0,0 +1,232 @@
+use crate::hint_processor::{registry::canonical_hint_code, HintModule, HintRegistry, HintRegistryError};
+use crate::hints::{EthEssentialsHints, HintFunction};
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::builtin_hint_processor_definition::HintProcessorData,
+    types::exec_scope::ExecutionScopes,
+    vm::{
+        errors::hint_errors::HintError,
+        vm_core::{VirtualMachine, VirtualMachineBuilder},
+    },
+    Felt252,
+};
+use std::collections::HashMap;
+
+/// A module registering `hints` with the given priority, each of them storing its module name in the
+/// `module` scope variable.
+struct TestModule {
+    name: &'static str,
+    priority: i32,
+    hints: Vec<&'static str>,
+}
+
+impl HintModule for TestModule {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn hints(&self) -> Vec<(&'static str, HintFunction)> {
+        let hint: HintFunction = match self.name {
+            "first" => hint_first,
+            _ => hint_second,
+        };
+        self.hints.iter().map(|code| (*code, hint)).collect()
+    }
+
+    fn priority(&self) -> i32 {
+        self.priority
+    }
+}
+
+fn hint_first(
+    _: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    _: &HintProcessorData,
+    _: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    exec_scopes.insert_value("module", "first");
+    Ok(())
+}
+
+fn hint_second(
+    _: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    _: &HintProcessorData,
+    _: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    exec_scopes.insert_value("module", "second");
+    Ok(())
+}
+
+fn module(name: &'static str, priority: i32, hints: &[&'static str]) -> TestModule {
+    TestModule {
+        name,
+        priority,
+        hints: hints.to_vec(),
+    }
+}
+
+/// The name of the module whose implementation of `code` runs, if any.
+fn run(registry: &HintRegistry, code: &str) -> Option<&'static str> {
+    let hint = registry.get(code)?;
+    let mut exec_scopes = ExecutionScopes::new();
+    let hint_data = HintProcessorData::new_default(code.to_string(), HashMap::new());
+    hint(
+        &mut VirtualMachineBuilder::default().build(),
+        &mut exec_scopes,
+        &hint_data,
+        &HashMap::new(),
+    )
+    .unwrap();
+    Some(exec_scopes.get::<&str>("module").unwrap())
+}
+
+#[test]
+fn test_priority_override() {
+    let mut registry = HintRegistry::empty();
+    registry.register_module(&module("first", 0, &["x = 1", "y = 1"])).unwrap();
+    registry.register_module(&module("second", 1, &["x  =  1"])).unwrap();
+
+    // The exact code of the replaced hint runs the new one too.
+    assert_eq!(run(&registry, "x = 1"), Some("second"));
+    assert_eq!(run(&registry, "x=1"), Some("second"));
+    assert_eq!(run(&registry, "y = 1"), Some("first"));
+    assert_eq!(registry.module("x = 1"), Some("second"));
+    let mut codes = registry.codes().collect::<Vec<_>>();
+    codes.sort();
+    assert_eq!(codes, ["x  =  1", "y = 1"]);
+
+    // A hint of lower priority is ignored.
+    let mut registry = HintRegistry::empty();
+    registry.register_module(&module("second", 1, &["x = 1"])).unwrap();
+    registry.register_module(&module("first", 0, &["x = 1", "y = 1"])).unwrap();
+    assert_eq!(run(&registry, "x = 1"), Some("second"));
+    assert_eq!(run(&registry, "y = 1"), Some("first"));
+    assert_eq!(run(&registry, "z = 1"), None);
+}
+
+#[test]
+fn test_equal_priority_conflict() {
+    let mut registry = HintRegistry::empty();
+    registry.register_module(&module("first", 0, &["x = 1"])).unwrap();
+    assert_eq!(
+        registry.register_module(&module("second", 0, &["y = 1", "x=1"])),
+        Err(HintRegistryError::Conflict {
+            code: "x=1".to_string(),
+            registered: "first".to_string(),
+            module: "second".to_string(),
+            priority: 0,
+        })
+    );
+    // Nothing of the conflicting module is registered.
+    assert_eq!(run(&registry, "x = 1"), Some("first"));
+    assert_eq!(run(&registry, "y = 1"), None);
+
+    // The hints of the crate conflict with themselves.
+    assert!(matches!(
+        HintRegistry::new().register_module(&EthEssentialsHints),
+        Err(HintRegistryError::Conflict { .. })
+    ));
+}
+
+#[test]
+fn test_codes_with_same_canonical_form() {
+    let mut registry = HintRegistry::empty();
+    assert_eq!(
+        registry.register_module(&module("first", 0, &["if x:\n    y = 1", "if x:\n  y=1"])),
+        Err(HintRegistryError::Conflict {
+            code: "if x:\n  y=1".to_string(),
+            registered: "first".to_string(),
+            module: "first".to_string(),
+            priority: 0,
+        })
+    );
+    assert_eq!(registry.codes().count(), 0);
+}
+
+#[test]
+fn test_reindentation() {
+    let canonical = "if x:\n    y=1\n    if z:\n        y=2\nelse:\n    y=3";
+    assert_eq!(canonical_hint_code(canonical), canonical);
+    assert_eq!(canonical_hint_code("if x:\n  y = 1\n  if z:\n     y = 2\nelse:\n y = 3"), canonical);
+    assert_eq!(canonical_hint_code("if x:\n\ty = 1\n\tif z:\n\t\ty = 2\nelse:\n\ty = 3"), canonical);
+    // A whole hint indented as in the Cairo sources.
+    assert_eq!(
+        canonical_hint_code("\n        if x:\n            y = 1\n            if z:\n                y = 2\n        else:\n            y = 3\n    "),
+        canonical
+    );
+}
+
+#[test]
+fn test_line_endings() {
+    assert_eq!(canonical_hint_code("if x:\r\n    y = 1\r\n\r\nz = 2\r\n"), "if x:\n    y=1\nz=2");
+    assert_eq!(canonical_hint_code("x = 1  \n\n\n   \ny = 2\t"), "x=1\ny=2");
+}
+
+#[test]
+fn test_operator_spacing() {
+    assert_eq!(canonical_hint_code("x = a + b * (c - 1)"), "x=a+b*(c-1)");
+    assert_eq!(canonical_hint_code("x=a+b*(c-1)"), "x=a+b*(c-1)");
+    assert_eq!(canonical_hint_code("ids.x = f( a , b [ 0 ] )"), "ids.x=f(a,b[0])");
+    // Spaces separating words are kept.
+    assert_eq!(canonical_hint_code("x = 1  if not  y else  2"), "x=1 if not y else 2");
+    assert_eq!(canonical_hint_code("for  i in  range(n):\n    pass"), "for i in range(n):\n    pass");
+}
+
+#[test]
+fn test_strings_and_comments() {
+    assert_eq!(canonical_hint_code("x = 'a  +  b' + \"c ' d\""), "x='a  +  b'+\"c ' d\"");
+    assert_eq!(canonical_hint_code(r#"x = "a \"  b" +  c"#), r#"x="a \"  b"+c"#);
+    assert_eq!(canonical_hint_code("x = 1  # a  comment,  'quoted'"), "x=1# a  comment,  'quoted'");
+    assert_eq!(canonical_hint_code("#  only a comment\nx = 1"), "#  only a comment\nx=1");
+    // Adjacent literals keep their separating space, so that `'' 'a'` does not read as a triple quote.
+    assert_eq!(canonical_hint_code("x = '' 'a'"), "x='' 'a'");
+}
+
+#[test]
+fn test_multi_line_strings() {
+    let code = "if x:\n    y = \"\"\"\n  a  +  b\n\n\tc  \n\"\"\" + z\n    w = '''\n'''";
+    assert_eq!(
+        canonical_hint_code(code),
+        "if x:\n    y=\"\"\"\n  a  +  b\n\n\tc  \n\"\"\"+z\n    w='''\n'''"
+    );
+    // Re-indenting the code around the strings leaves them as written.
+    assert_eq!(
+        canonical_hint_code(&code.replace("\n    y", "\n  y").replace("\n    w", "\n  w")),
+        canonical_hint_code(code)
+    );
+    // A string continued on the next line by an escaped line ending.
+    assert_eq!(canonical_hint_code("x = 'a \\\n  b'\ny = 1"), "x='a \\\n  b'\ny=1");
+}
+
+#[test]
+fn test_canonical_forms_are_stable() {
+    for (code, _) in EthEssentialsHints.hints() {
+        let canonical = canonical_hint_code(code);
+        assert_eq!(canonical_hint_code(&canonical), canonical, "{code}");
+    }
+}
+
+#[test]
+fn test_distinct_hints_have_distinct_forms() {
+    let mut forms = HashMap::new();
+    for (code, _) in EthEssentialsHints.hints() {
+        if let Some(other) = forms.insert(canonical_hint_code(code), code) {
+            panic!("{code:?} and {other:?} have the same canonical form");
+        }
+    }
+
+    // Hints differing in more than formatting.
+    let distinct = [
+        ("x = not y", "x = noty"),
+        ("x = 'a b'", "x = 'ab'"),
+        ("x = '' 'a'", "x = '''a'"),
+        ("x = 1  # a b", "x = 1  # ab"),
+        ("if x:\n    y = 1\nz = 2", "if x:\n    y = 1\n    z = 2"),
+        ("if x:\n  if y:\n    z = 1\n  w = 2", "if x:\n  if y:\n    z = 1\n    w = 2"),
+        ("x = '''\n  a\n'''", "x = '''\na\n'''"),
+        ("x = '''\n\n'''", "x = '''\n'''"),
+    ];
+    for (code, other) in distinct {
+        assert_ne!(canonical_hint_code(code), canonical_hint_code(other), "{code:?}");
+    }
+}
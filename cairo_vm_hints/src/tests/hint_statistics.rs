@@ -0,0 +1,80 @@
+use crate::hint_processor::HintModule;
+use crate::hint_processor::{HintRegistry, HintStatistics};
+use crate::hints::EthEssentialsHints;
+use crate::ExtendedHintProcessor;
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::{builtin_hint_processor_definition::HintFunc, hint_code},
+        hint_processor_definition::HintProcessorLogic,
+    },
+    serde::deserialize_program::ApTracking,
+    types::{exec_scope::ExecutionScopes, relocatable::Relocatable},
+    vm::{context::run_context::RunContext, vm_core::VirtualMachineBuilder},
+};
+use std::{collections::HashMap, rc::Rc, thread::sleep, time::Duration};
+
+#[test]
+fn test_statistics_report() {
+    let mut statistics = HintStatistics::default();
+    statistics.record("x = 1", Duration::from_millis(3));
+    statistics.record("y = 1", Duration::from_millis(10));
+    statistics.record("x = 1", Duration::from_millis(4));
+
+    let stats = statistics.get("x = 1").unwrap();
+    assert_eq!(stats.executions, 2);
+    assert_eq!(stats.total_time, Duration::from_millis(7));
+    assert!(statistics.get("z = 1").is_none());
+
+    // A registered hint ran under another formatting of its code.
+    let report = statistics.report(["x=1", "w = 1", "z = 1", "y = 1"]);
+    let hints = report
+        .hints
+        .iter()
+        .map(|entry| (entry.code.as_str(), entry.executions, entry.total_time_ns))
+        .collect::<Vec<_>>();
+    assert_eq!(hints, [("y = 1", 1, 10_000_000), ("x = 1", 2, 7_000_000)]);
+    assert_eq!(report.unused, ["w = 1", "z = 1"]);
+}
+
+#[test]
+fn test_hint_processor_statistics() {
+    let mut hint_processor = ExtendedHintProcessor::new();
+    assert!(hint_processor.statistics_report().is_none());
+    hint_processor.enable_statistics();
+    let sleep_hint = HintFunc(Box::new(|_, _, _, _, _| {
+        sleep(Duration::from_millis(5));
+        Ok(())
+    }));
+    hint_processor.add_hint("sleep()".to_string(), Rc::new(sleep_hint));
+
+    let mut vm = VirtualMachineBuilder::default()
+        .run_context(RunContext::new(Relocatable::from((0, 0)), 0, 0))
+        .build();
+    vm.segments.add();
+    vm.segments.add();
+    // The hint of the crate fails for lack of variables, which is counted all the same.
+    let (registered, _) = EthEssentialsHints.hints()[0];
+    for code in ["sleep()", hint_code::ADD_SEGMENT, "sleep()", registered] {
+        let hint_data = hint_processor.compile_hint(code, &ApTracking::new(), &HashMap::new(), &[]).unwrap();
+        let _ = hint_processor.execute_hint_extensive(&mut vm, &mut ExecutionScopes::new(), &hint_data, &HashMap::new());
+    }
+
+    let report = hint_processor.statistics_report().unwrap();
+    assert_eq!(report.hints[0].code, "sleep()");
+    assert_eq!(report.hints[0].executions, 2);
+    assert!(report.hints[0].total_time_ns >= 10_000_000);
+    let mut executed = report
+        .hints
+        .iter()
+        .map(|entry| (entry.code.as_str(), entry.executions))
+        .collect::<Vec<_>>();
+    executed.sort();
+    let mut expected = vec![("sleep()", 2), (hint_code::ADD_SEGMENT, 1), (registered, 1)];
+    expected.sort();
+    assert_eq!(executed, expected);
+
+    let registry = HintRegistry::new();
+    let mut unused = registry.codes().filter(|code| *code != registered).collect::<Vec<_>>();
+    unused.sort();
+    assert_eq!(report.unused, unused);
+}
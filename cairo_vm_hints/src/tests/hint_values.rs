@@ -0,0 +1,130 @@
+use crate::hints::lib::{rlp_little::leading_zeros::hint_expected_nibble, utils::divmod::hint_value_div};
+use crate::hints::{HintFunction, HintValueError};
+use crate::utils::{felt_to, non_zero};
+use cairo_vm::{
+    hint_processor::{builtin_hint_processor::builtin_hint_processor_definition::HintProcessorData, hint_processor_definition::HintReference},
+    types::{exec_scope::ExecutionScopes, relocatable::Relocatable},
+    vm::{context::run_context::RunContext, errors::hint_errors::HintError, vm_core::VirtualMachineBuilder},
+    Felt252,
+};
+use std::collections::HashMap;
+
+/// Runs `hint` with the variables at `fp + offset` holding `values`.
+fn run_hint(hint: HintFunction, variables: &[(&str, i32)], values: &[Felt252]) -> Result<(), HintError> {
+    let mut vm = VirtualMachineBuilder::default()
+        .run_context(RunContext::new(Relocatable::from((0, 0)), 0, 0))
+        .build();
+    vm.segments.add();
+    vm.segments.add();
+    for (offset, value) in values.iter().enumerate() {
+        vm.insert_value(Relocatable::from((1, offset)), *value).unwrap();
+    }
+    let ids_data = variables
+        .iter()
+        .map(|(name, offset)| (name.to_string(), HintReference::new_simple(*offset)))
+        .collect();
+    hint(
+        &mut vm,
+        &mut ExecutionScopes::new(),
+        &HintProcessorData::new_default(String::new(), ids_data),
+        &HashMap::new(),
+    )
+}
+
+#[test]
+fn test_felt_to() {
+    assert_eq!(felt_to::<u64>("hint", "x", Felt252::from(u64::MAX)), Ok(u64::MAX));
+    assert_eq!(felt_to::<u128>("hint", "x", Felt252::from(u128::MAX)), Ok(u128::MAX));
+    assert_eq!(felt_to::<usize>("hint", "x", Felt252::ZERO), Ok(0));
+
+    let out_of_range = |value, ty| HintValueError::OutOfRange {
+        hint: "hint",
+        variable: "x",
+        value,
+        ty,
+    };
+    let value = Felt252::from(u64::MAX) + 1;
+    assert_eq!(felt_to::<u64>("hint", "x", value), Err(out_of_range(value, "u64")));
+    let value = Felt252::from(u128::MAX) + 1;
+    assert_eq!(felt_to::<u128>("hint", "x", value), Err(out_of_range(value, "u128")));
+    assert_eq!(felt_to::<u128>("hint", "x", -Felt252::ONE), Err(out_of_range(-Felt252::ONE, "u128")));
+    assert_eq!(
+        felt_to::<u8>("hint", "x", Felt252::from(256)),
+        Err(out_of_range(Felt252::from(256), "u8"))
+    );
+}
+
+#[test]
+fn test_non_zero() {
+    assert!(non_zero("hint", "x", Felt252::ONE).is_ok());
+    assert_eq!(
+        non_zero("hint", "x", Felt252::ZERO).unwrap_err(),
+        HintValueError::Zero { hint: "hint", variable: "x" }
+    );
+}
+
+#[test]
+fn test_hint_errors() {
+    let error = run_hint(hint_value_div, &[("value", 0), ("div", 1)], &[Felt252::from(7), Felt252::ZERO]).unwrap_err();
+    assert_eq!(
+        HintValueError::from_hint_error(&error),
+        Some(&HintValueError::Zero {
+            hint: "hint_value_div",
+            variable: "div"
+        })
+    );
+    assert_eq!(error.to_string(), "hint_value_div: div must not be zero");
+
+    let nibble_variables = [("key", 0), ("nibble_index", 2)];
+    let error = run_hint(
+        hint_expected_nibble,
+        &nibble_variables,
+        &[Felt252::from(u128::MAX) + 1, Felt252::ZERO, Felt252::ZERO],
+    )
+    .unwrap_err();
+    assert_eq!(
+        HintValueError::from_hint_error(&error),
+        Some(&HintValueError::OutOfRange {
+            hint: "hint_expected_nibble",
+            variable: "key.low",
+            value: Felt252::from(u128::MAX) + 1,
+            ty: "u128",
+        })
+    );
+
+    let error = run_hint(
+        hint_expected_nibble,
+        &nibble_variables,
+        &[Felt252::from(0x12), Felt252::ZERO, -Felt252::ONE],
+    )
+    .unwrap_err();
+    assert_eq!(
+        HintValueError::from_hint_error(&error),
+        Some(&HintValueError::OutOfRange {
+            hint: "hint_expected_nibble",
+            variable: "nibble_index",
+            value: -Felt252::ONE,
+            ty: "usize",
+        })
+    );
+
+    let error = run_hint(
+        hint_expected_nibble,
+        &nibble_variables,
+        &[Felt252::from(0x12), Felt252::ZERO, Felt252::from(2)],
+    )
+    .unwrap_err();
+    assert_eq!(
+        HintValueError::from_hint_error(&error),
+        Some(&HintValueError::OutOfBounds {
+            hint: "hint_expected_nibble",
+            variable: "nibble_index",
+            value: 2,
+            limit: 2,
+        })
+    );
+
+    // Other errors are not hint value errors.
+    let error = run_hint(hint_value_div, &[("value", 0)], &[Felt252::from(7)]).unwrap_err();
+    assert_eq!(HintValueError::from_hint_error(&error), None);
+}
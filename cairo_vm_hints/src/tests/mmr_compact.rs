@@ -0,0 +1,27 @@
+use crate::mmr::{CompactMmr, Mmr, MmrError, Poseidon};
+use starknet_types_core::felt::Felt;
+
+#[test]
+fn test_empty_root() {
+    assert!(matches!(CompactMmr::<Poseidon>::new().get_root(), Err(MmrError::EmptyMmr)));
+    assert!(matches!(
+        CompactMmr::<Poseidon>::from_peaks(0, vec![]).unwrap().get_root(),
+        Err(MmrError::EmptyMmr)
+    ));
+    assert!(matches!(Mmr::<Poseidon>::new().get_root(), Err(MmrError::EmptyMmr)));
+}
+
+#[test]
+fn test_resume_from_peaks() {
+    let mut mmr = Mmr::<Poseidon>::new();
+    let mut compact = CompactMmr::<Poseidon>::from_peaks(0, vec![]).unwrap();
+    for leaf in 0..20 {
+        mmr.append(Felt::from(leaf)).unwrap();
+        compact.append(Felt::from(leaf));
+
+        let resumed = CompactMmr::<Poseidon>::from_peaks(compact.size(), compact.get_peak_values().to_vec()).unwrap();
+        assert_eq!(resumed.get_root().unwrap(), mmr.get_root().unwrap());
+        assert_eq!(compact.get_root().unwrap(), mmr.get_root().unwrap());
+    }
+    assert!(CompactMmr::<Poseidon>::from_peaks(mmr.size() + 1, vec![]).is_none());
+}
@@ -0,0 +1,48 @@
+use crate::mmr::{DualMmr, Hasher, Keccak, MemoryStore, MmrError, Poseidon, Store};
+use starknet_types_core::felt::Felt;
+
+fn keccak_leaf(value: u8) -> [u8; 32] {
+    [value; 32]
+}
+
+/// The root of `tools/py/mmr.py` and `lib/mmr.cairo`: the MMR size hashed with the peaks bagged from the right.
+fn expected_root<H: Hasher>(size: H::Digest, peaks: &[H::Digest]) -> H::Digest {
+    let hasher = H::new();
+    let (last, rest) = peaks.split_last().unwrap();
+    let bagged = rest.iter().rev().fold(last.clone(), |bagged, peak| hasher.hash(peak, &bagged));
+    hasher.hash(&size, &bagged)
+}
+
+#[test]
+fn test_roots() {
+    let poseidon = Poseidon::new();
+    let keccak = Keccak::new();
+    let mut mmr = DualMmr::new();
+    for value in 1..=3 {
+        mmr.append(Felt::from(value), keccak_leaf(value)).unwrap();
+    }
+
+    // Leaves 1 and 2 are merged into the first peak, leaf 3 is the second one.
+    let state = mmr.get_state().unwrap();
+    let poseidon_peaks = [poseidon.hash(&Felt::from(1), &Felt::from(2)), Felt::from(3)];
+    let keccak_peaks = [keccak.hash(&keccak_leaf(1), &keccak_leaf(2)), keccak_leaf(3)];
+    let mut keccak_size = [0u8; 32];
+    keccak_size[31] = 4;
+
+    assert_eq!(state.size, 4);
+    assert_eq!(state.peaks_poseidon, poseidon_peaks);
+    assert_eq!(state.peaks_keccak, keccak_peaks);
+    assert_eq!(state.root_poseidon, expected_root::<Poseidon>(Felt::from(4), &poseidon_peaks));
+    assert_eq!(state.root_keccak, expected_root::<Keccak>(keccak_size, &keccak_peaks));
+}
+
+#[test]
+fn test_mismatched_stores() {
+    let mut poseidon_store = MemoryStore::default();
+    poseidon_store.extend(&[Felt::ONE]).unwrap();
+    let keccak_store = MemoryStore::<[u8; 32]>::default();
+    assert!(matches!(
+        DualMmr::with_stores(poseidon_store, keccak_store),
+        Err(MmrError::InvalidSize(0))
+    ));
+}
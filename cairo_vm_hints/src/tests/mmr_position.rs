@@ -0,0 +1,75 @@
+use super::build_mmr;
+use crate::mmr::position::{
+    get_peaks, is_valid_mmr_size, leaf_count_to_mmr_size, leaf_index_to_pos, left_child, left_peak_height_pos, mmr_size_to_leaf_count,
+    pos_to_leaf_index, right_child, tree_pos_height,
+};
+use crate::mmr::{MemoryStore, Mmr, MmrError, Poseidon, Store};
+use starknet_types_core::felt::Felt;
+
+#[test]
+fn test_heights() {
+    let heights = [0, 0, 1, 0, 0, 1, 2, 0, 0, 1, 0, 0, 1, 2, 3, 0, 0, 1, 0];
+    for (pos, height) in heights.into_iter().enumerate() {
+        assert_eq!(tree_pos_height(pos), height, "height of position {}", pos);
+    }
+    assert_eq!(left_child(14), Some(6));
+    assert_eq!(right_child(14), Some(13));
+    assert_eq!(left_child(2), Some(0));
+    assert_eq!(right_child(2), Some(1));
+    assert_eq!(left_child(3), None);
+}
+
+#[test]
+fn test_leaf_positions() {
+    let leaf_positions = [0, 1, 3, 4, 7, 8, 10, 11, 15, 16, 18];
+    for (leaf_index, pos) in leaf_positions.into_iter().enumerate() {
+        assert_eq!(leaf_index_to_pos(leaf_index), pos);
+        assert_eq!(pos_to_leaf_index(pos), Some(leaf_index));
+    }
+    for pos in [2, 5, 6, 9, 12, 13, 14, 17] {
+        assert_eq!(pos_to_leaf_index(pos), None);
+    }
+}
+
+#[test]
+fn test_sizes() {
+    assert_eq!(get_peaks(11), Some(vec![6, 9, 10]));
+    assert_eq!(get_peaks(19), Some(vec![14, 17, 18]));
+    assert_eq!(get_peaks(0), Some(vec![]));
+    assert_eq!(left_peak_height_pos(19), Some((3, 14)));
+    assert_eq!(left_peak_height_pos(0), None);
+
+    for leaf_count in 1..100 {
+        let size = leaf_count_to_mmr_size(leaf_count);
+        assert!(is_valid_mmr_size(size));
+        assert_eq!(mmr_size_to_leaf_count(size), Some(leaf_count));
+    }
+}
+
+#[test]
+fn test_wrong_size() {
+    for size in [2, 5, 6, 9, 12, 13, 14, 17, 20] {
+        assert!(!is_valid_mmr_size(size));
+        assert_eq!(get_peaks(size), None);
+        assert_eq!(mmr_size_to_leaf_count(size), None);
+    }
+    assert!(!is_valid_mmr_size(0));
+
+    let mut store = MemoryStore::default();
+    store.extend(&[Felt::ONE, Felt::TWO]).unwrap();
+    assert!(matches!(Mmr::<Poseidon, _>::with_store(store), Err(MmrError::InvalidSize(2))));
+}
+
+#[test]
+fn test_node_indices() {
+    // `Mmr` and the Cairo programs index nodes from 1, the index of a node being its position plus one.
+    let mmr = build_mmr(11);
+    let peak_positions = get_peaks(mmr.size()).unwrap();
+    assert_eq!(mmr.get_peaks(), peak_positions.iter().map(|pos| pos + 1).collect::<Vec<_>>());
+
+    for leaf_index in 0..11 {
+        let element_index = mmr.get_proof(leaf_index).unwrap().element_index;
+        assert_eq!(element_index, leaf_index_to_pos(leaf_index) + 1);
+        assert_eq!(mmr.retrieve_nodes(vec![element_index]).unwrap(), [Felt::from(leaf_index)]);
+    }
+}
@@ -0,0 +1,143 @@
+use super::build_mmr;
+use crate::mmr::{verify_batch_proof, verify_consistency, verify_proof, Poseidon};
+use starknet_types_core::felt::Felt;
+
+#[test]
+fn test_inclusion_proofs() {
+    for leaf_count in 1..=33 {
+        let mmr = build_mmr(leaf_count);
+        let root = mmr.get_root().unwrap();
+        for leaf in 0..leaf_count {
+            let proof = mmr.get_proof(leaf).unwrap();
+            assert!(verify_proof::<Poseidon>(&root, mmr.size(), &Felt::from(leaf), &proof));
+            assert!(!verify_proof::<Poseidon>(&root, mmr.size(), &Felt::from(leaf + 1), &proof));
+        }
+        assert!(mmr.get_proof(leaf_count).is_err());
+    }
+}
+
+#[test]
+fn test_batch_proofs() {
+    for leaf_count in 1..=20 {
+        let mmr = build_mmr(leaf_count);
+        let root = mmr.get_root().unwrap();
+        for first in 0..leaf_count {
+            for last in first..leaf_count {
+                let leaf_indices = [last, first, (first + last) / 2];
+                let proof = mmr.get_batch_proof(&leaf_indices).unwrap();
+                let leaves = proof
+                    .element_indices
+                    .iter()
+                    .map(|index| Felt::from(crate::mmr::position::pos_to_leaf_index(index - 1).unwrap()))
+                    .collect::<Vec<_>>();
+                assert!(verify_batch_proof::<Poseidon>(&root, mmr.size(), &leaves, &proof));
+
+                let mut wrong_leaves = leaves.clone();
+                wrong_leaves[0] += Felt::ONE;
+                assert!(!verify_batch_proof::<Poseidon>(&root, mmr.size(), &wrong_leaves, &proof));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_consistency_proofs() {
+    let leaf_counts = 1..=25;
+    let mmrs = leaf_counts.clone().map(build_mmr).collect::<Vec<_>>();
+    for old in &mmrs {
+        for new in mmrs.iter().filter(|new| new.size() >= old.size()) {
+            let proof = new.get_consistency_proof(old.size()).unwrap();
+            assert!(verify_consistency::<Poseidon>(
+                &old.get_root().unwrap(),
+                old.size(),
+                &new.get_root().unwrap(),
+                new.size(),
+                &proof
+            ));
+        }
+    }
+}
+
+#[test]
+fn test_wrong_sibling() {
+    let mmr = build_mmr(11);
+    let root = mmr.get_root().unwrap();
+
+    let mut proof = mmr.get_proof(4).unwrap();
+    proof.siblings[1] += Felt::ONE;
+    assert!(!verify_proof::<Poseidon>(&root, mmr.size(), &Felt::from(4), &proof));
+
+    let mut batch_proof = mmr.get_batch_proof(&[0, 4]).unwrap();
+    batch_proof.siblings[0] += Felt::ONE;
+    assert!(!verify_batch_proof::<Poseidon>(
+        &root,
+        mmr.size(),
+        &[Felt::from(0), Felt::from(4)],
+        &batch_proof
+    ));
+
+    let old = build_mmr(5);
+    let mut consistency_proof = mmr.get_consistency_proof(old.size()).unwrap();
+    consistency_proof.paths[0][0] += Felt::ONE;
+    assert!(!verify_consistency::<Poseidon>(
+        &old.get_root().unwrap(),
+        old.size(),
+        &root,
+        mmr.size(),
+        &consistency_proof
+    ));
+}
+
+#[test]
+fn test_wrong_size() {
+    let mmr = build_mmr(11);
+    let root = mmr.get_root().unwrap();
+    let proof = mmr.get_proof(4).unwrap();
+
+    // 18 is the size of the MMR of 10 leaves, 20 is not a valid size.
+    for size in [mmr.size() - 1, 18, 20, mmr.size() + 1] {
+        assert!(!verify_proof::<Poseidon>(&root, size, &Felt::from(4), &proof));
+    }
+
+    let batch_proof = mmr.get_batch_proof(&[4]).unwrap();
+    assert!(!verify_batch_proof::<Poseidon>(&root, 18, &[Felt::from(4)], &batch_proof));
+
+    let old = build_mmr(5);
+    let consistency_proof = mmr.get_consistency_proof(old.size()).unwrap();
+    let old_root = old.get_root().unwrap();
+    assert!(!verify_consistency::<Poseidon>(
+        &old_root,
+        old.size() + 1,
+        &root,
+        mmr.size(),
+        &consistency_proof
+    ));
+    assert!(!verify_consistency::<Poseidon>(&old_root, old.size(), &root, 18, &consistency_proof));
+    assert!(mmr.get_consistency_proof(mmr.size() + 1).is_err());
+    assert!(mmr.get_consistency_proof(0).is_err());
+}
+
+#[test]
+fn test_peak_not_in_mmr() {
+    let mmr = build_mmr(11);
+    let root = mmr.get_root().unwrap();
+    let foreign_peaks = build_mmr(12).get_proof(0).unwrap().peaks;
+
+    let mut proof = mmr.get_proof(9).unwrap();
+    *proof.peaks.last_mut().unwrap() = Felt::from(42);
+    assert!(!verify_proof::<Poseidon>(&root, mmr.size(), &Felt::from(9), &proof));
+
+    // Peaks of a larger MMR, under which the leaf is also included.
+    let mut proof = mmr.get_proof(0).unwrap();
+    proof.peaks = foreign_peaks;
+    assert!(!verify_proof::<Poseidon>(&root, mmr.size(), &Felt::from(0), &proof));
+
+    let mut batch_proof = mmr.get_batch_proof(&[0, 9]).unwrap();
+    batch_proof.peaks[0] = Felt::from(42);
+    assert!(!verify_batch_proof::<Poseidon>(
+        &root,
+        mmr.size(),
+        &[Felt::from(0), Felt::from(9)],
+        &batch_proof
+    ));
+}
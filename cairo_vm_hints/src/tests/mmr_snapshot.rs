@@ -0,0 +1,75 @@
+use super::build_mmr;
+use crate::mmr::{CompactMmr, HasherKind, Keccak, Mmr, MmrError, Poseidon};
+
+#[test]
+fn test_round_trip() {
+    for leaf_count in [0, 1, 2, 7, 8, 19] {
+        let mmr = build_mmr(leaf_count);
+        let snapshot = mmr.to_snapshot().unwrap();
+
+        let restored = Mmr::<Poseidon>::from_snapshot(&snapshot).unwrap();
+        assert_eq!(restored.size(), mmr.size());
+        assert_eq!(restored.to_snapshot().unwrap(), snapshot);
+
+        let compact = CompactMmr::<Poseidon>::from_snapshot(&snapshot).unwrap();
+        assert_eq!(compact.size(), mmr.size());
+        assert_eq!(
+            CompactMmr::<Poseidon>::from_snapshot(&compact.to_snapshot().unwrap()).unwrap().size(),
+            mmr.size()
+        );
+        if leaf_count != 0 {
+            assert_eq!(restored.get_root().unwrap(), mmr.get_root().unwrap());
+            assert_eq!(compact.get_root().unwrap(), mmr.get_root().unwrap());
+        }
+    }
+
+    let compact = CompactMmr::try_from(&build_mmr(5)).unwrap();
+    assert!(matches!(
+        Mmr::<Poseidon>::from_snapshot(&compact.to_snapshot().unwrap()),
+        Err(MmrError::MissingNodes)
+    ));
+}
+
+#[test]
+fn test_wrong_magic() {
+    let mut snapshot = build_mmr(3).to_snapshot().unwrap();
+    snapshot[0] ^= 1;
+    assert!(matches!(Mmr::<Poseidon>::from_snapshot(&snapshot), Err(MmrError::MalformedSnapshot)));
+}
+
+#[test]
+fn test_wrong_version() {
+    let mut snapshot = build_mmr(3).to_snapshot().unwrap();
+    // The version follows the 4 magic bytes, as a single-byte varint.
+    assert_eq!(snapshot[4], 1);
+    snapshot[4] = 2;
+    assert!(matches!(
+        Mmr::<Poseidon>::from_snapshot(&snapshot),
+        Err(MmrError::UnsupportedSnapshotVersion(2))
+    ));
+}
+
+#[test]
+fn test_wrong_hasher() {
+    let snapshot = build_mmr(3).to_snapshot().unwrap();
+    assert!(matches!(
+        Mmr::<Keccak>::from_snapshot(&snapshot),
+        Err(MmrError::HasherMismatch {
+            expected: HasherKind::Keccak,
+            found: HasherKind::Poseidon
+        })
+    ));
+}
+
+#[test]
+fn test_truncated() {
+    let snapshot = build_mmr(4).to_snapshot().unwrap();
+    for len in 0..snapshot.len() {
+        assert!(Mmr::<Poseidon>::from_snapshot(&snapshot[..len]).is_err());
+        assert!(CompactMmr::<Poseidon>::from_snapshot(&snapshot[..len]).is_err());
+    }
+
+    let mut extended = snapshot.clone();
+    extended.push(0);
+    assert!(matches!(Mmr::<Poseidon>::from_snapshot(&extended), Err(MmrError::MalformedSnapshot)));
+}
@@ -0,0 +1,123 @@
+use super::build_mmr;
+use crate::mmr::{FileStore, Keccak, Mmr, Poseidon, Store};
+use starknet_types_core::felt::Felt;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+
+const HEADER_SIZE: u64 = 8;
+const RECORD_SIZE: u64 = 36;
+
+fn store_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("mmr_store_{}_{}", std::process::id(), name));
+    let _ = fs::remove_file(&path);
+    path
+}
+
+fn open(path: &PathBuf) -> Mmr<Poseidon, FileStore<Felt>> {
+    Mmr::with_store(FileStore::open::<Poseidon>(path).unwrap()).unwrap()
+}
+
+fn append_leaves(mmr: &mut Mmr<Poseidon, FileStore<Felt>>, leaves: std::ops::Range<usize>) {
+    for leaf in leaves {
+        mmr.append(Felt::from(leaf)).unwrap();
+    }
+}
+
+#[test]
+fn test_reopen() {
+    let path = store_path("reopen");
+    let mut mmr = open(&path);
+    append_leaves(&mut mmr, 0..10);
+    let size = mmr.size();
+    drop(mmr);
+
+    let mut mmr = open(&path);
+    assert_eq!(mmr.size(), size);
+    assert_eq!(mmr.get_root().unwrap(), build_mmr(10).get_root().unwrap());
+    append_leaves(&mut mmr, 10..16);
+    drop(mmr);
+
+    assert_eq!(open(&path).get_root().unwrap(), build_mmr(16).get_root().unwrap());
+    assert_eq!(fs::metadata(&path).unwrap().len(), HEADER_SIZE + 31 * RECORD_SIZE);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_truncated_tail() {
+    let path = store_path("truncated");
+    let mut mmr = open(&path);
+    append_leaves(&mut mmr, 0..8);
+    drop(mmr);
+
+    // The append of the 8th leaf wrote 4 nodes, cut in the middle of the last one.
+    let file = OpenOptions::new().write(true).open(&path).unwrap();
+    file.set_len(HEADER_SIZE + 15 * RECORD_SIZE - 5).unwrap();
+    drop(file);
+
+    let mmr = open(&path);
+    assert_eq!(mmr.size(), 11);
+    assert_eq!(mmr.get_root().unwrap(), build_mmr(7).get_root().unwrap());
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_corrupted_tail() {
+    let path = store_path("corrupted");
+    let mut mmr = open(&path);
+    append_leaves(&mut mmr, 0..8);
+    drop(mmr);
+
+    // Zero-fill a node written by the last append, keeping the size of the file valid.
+    let mut file = OpenOptions::new().write(true).open(&path).unwrap();
+    file.seek(SeekFrom::Start(HEADER_SIZE + 12 * RECORD_SIZE)).unwrap();
+    file.write_all(&[0u8; RECORD_SIZE as usize]).unwrap();
+    drop(file);
+
+    let mut mmr = open(&path);
+    assert_eq!(mmr.size(), 11);
+    assert_eq!(mmr.get_root().unwrap(), build_mmr(7).get_root().unwrap());
+    append_leaves(&mut mmr, 7..8);
+    assert_eq!(mmr.get_root().unwrap(), build_mmr(8).get_root().unwrap());
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_corrupted_node() {
+    let path = store_path("corrupted_node");
+    let mut mmr = open(&path);
+    append_leaves(&mut mmr, 0..8);
+    drop(mmr);
+
+    // Corruption outside of the last append is reported when the node is read.
+    let mut file = OpenOptions::new().write(true).open(&path).unwrap();
+    file.seek(SeekFrom::Start(HEADER_SIZE + 2 * RECORD_SIZE)).unwrap();
+    file.write_all(&[1]).unwrap();
+    drop(file);
+
+    let store = FileStore::<Felt>::open::<Poseidon>(&path).unwrap();
+    assert_eq!(store.len(), 15);
+    assert_eq!(store.get(2).unwrap_err().kind(), ErrorKind::InvalidData);
+    assert!(store.get(3).is_ok());
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_header() {
+    let path = store_path("header");
+    let mut mmr = open(&path);
+    append_leaves(&mut mmr, 0..3);
+    drop(mmr);
+
+    assert_eq!(FileStore::<[u8; 32]>::open::<Keccak>(&path).unwrap_err().kind(), ErrorKind::InvalidData);
+
+    let mut file = OpenOptions::new().write(true).open(&path).unwrap();
+    file.seek(SeekFrom::Start(4)).unwrap();
+    file.write_all(&2u16.to_le_bytes()).unwrap();
+    drop(file);
+    assert_eq!(FileStore::<Felt>::open::<Poseidon>(&path).unwrap_err().kind(), ErrorKind::InvalidData);
+
+    fs::write(&path, b"not an MMR").unwrap();
+    assert_eq!(FileStore::<Felt>::open::<Poseidon>(&path).unwrap_err().kind(), ErrorKind::InvalidData);
+    fs::remove_file(&path).unwrap();
+}
@@ -1,24 +1,58 @@
+pub mod block_headers;
 pub mod construct_mmr;
 pub mod dw_hack;
 pub mod encode_packed_256;
+pub mod hint_registry;
+pub mod hint_statistics;
+pub mod hint_values;
 pub mod is_valid_mmr_size;
+pub mod mmr_compact;
+pub mod mmr_dual;
+pub mod mmr_position;
+pub mod mmr_proof;
+pub mod mmr_snapshot;
+pub mod mmr_store;
+pub mod mpt_get_proof;
+pub mod mpt_node;
+pub mod mpt_storage;
+pub mod mpt_trie;
+pub mod mpt_verify;
+pub mod program_input;
+pub mod unknown_hints;
 
-use crate::ExtendedHintProcessor;
+use crate::mmr::{Mmr, Poseidon};
+use crate::{hint_processor::ProgramInput, ExtendedHintProcessor};
 use cairo_vm::{
     cairo_run,
     vm::{errors::cairo_run_errors::CairoRunError, runners::cairo_runner::CairoRunner},
 };
+use starknet_types_core::felt::Felt;
 
-pub fn run_cairo_program(program_content: &[u8]) -> Result<CairoRunner, CairoRunError> {
+pub fn run_cairo_program(program_content: &[u8]) -> Result<CairoRunner, Box<CairoRunError>> {
+    run_with_hint_processor(program_content, &mut ExtendedHintProcessor::new())
+}
+
+pub fn run_cairo_program_with_input(program_content: &[u8], program_input: ProgramInput) -> Result<CairoRunner, Box<CairoRunError>> {
+    let mut hint_processor = ExtendedHintProcessor::new();
+    hint_processor.set_program_input(program_input);
+    run_with_hint_processor(program_content, &mut hint_processor)
+}
+
+fn run_with_hint_processor(program_content: &[u8], hint_processor: &mut ExtendedHintProcessor) -> Result<CairoRunner, Box<CairoRunError>> {
     let cairo_run_config = cairo_run::CairoRunConfig {
         layout: cairo_vm::types::layout_name::LayoutName::all_cairo,
         allow_missing_builtins: Some(true),
         ..Default::default()
     };
 
-    Ok(cairo_run::cairo_run(
-        program_content,
-        &cairo_run_config,
-        &mut ExtendedHintProcessor::new(),
-    )?)
+    cairo_run::cairo_run(program_content, &cairo_run_config, hint_processor).map_err(Box::new)
+}
+
+/// An MMR of the leaves `0..leaf_count`.
+pub fn build_mmr(leaf_count: usize) -> Mmr<Poseidon> {
+    let mut mmr = Mmr::<Poseidon>::new();
+    for leaf in 0..leaf_count {
+        mmr.append(Felt::from(leaf)).unwrap();
+    }
+    mmr
 }
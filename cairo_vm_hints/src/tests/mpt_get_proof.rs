@@ -0,0 +1,41 @@
+use crate::mpt::{verify_account_proof, AccountProofInput, GetProofResponse, StorageProofInput};
+
+/// State root of the block of `fixtures/get_proof_response.json`, which the response does not hold.
+const STATE_ROOT: [u8; 32] = [
+    0x09, 0xf4, 0x42, 0x47, 0x92, 0x95, 0x76, 0x1c, 0x7f, 0x9c, 0x5f, 0x87, 0x08, 0xa9, 0x01, 0xb5, 0x80, 0x68, 0x27, 0x71, 0x9d, 0xd7, 0xdf, 0xa3,
+    0xf1, 0xc3, 0x14, 0x73, 0xbc, 0x0a, 0x4b, 0xfe,
+];
+
+fn response() -> GetProofResponse {
+    GetProofResponse::from_json(include_str!("fixtures/get_proof_response.json")).unwrap()
+}
+
+/// `fixtures/get_proof_account_input.json` is the output of `tools/py/storage_proof.py::get_account_proof`
+/// for the same response, serialized with `json.dumps(dataclasses.asdict(proof), indent=2)`.
+#[test]
+fn test_account_input_matches_python() {
+    let python_output = include_str!("fixtures/get_proof_account_input.json");
+    let account = response().account_proof_input(STATE_ROOT);
+
+    assert_eq!(serde_json::from_str::<AccountProofInput>(python_output).unwrap(), account);
+    assert_eq!(serde_json::to_string_pretty(&account).unwrap(), python_output.trim_end());
+}
+
+#[test]
+fn test_response_verifies() {
+    let response = response();
+    let account = verify_account_proof(STATE_ROOT, &response.address, &response.account_proof)
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.storage_root, response.storage_hash);
+    assert_eq!(account.code_hash, response.code_hash);
+}
+
+#[test]
+fn test_storage_input_round_trip() {
+    for storage in response().storage_proof_inputs() {
+        let json = serde_json::to_string(&storage).unwrap();
+        assert!(json.starts_with(&format!("{{\"root\":[{},{}]", storage.root.low, storage.root.high)));
+        assert_eq!(serde_json::from_str::<StorageProofInput>(&json).unwrap(), storage);
+    }
+}
@@ -0,0 +1,238 @@
+use crate::mpt::{bytes_to_le_chunks, key_from_index, rlp, Child, MptError, Node, NodeError, RlpError, Trie};
+
+fn leaf(encoded_path: &[u8], value: &[u8]) -> Vec<u8> {
+    rlp::encode_list(&[rlp::encode_bytes(encoded_path), rlp::encode_bytes(value)])
+}
+
+fn branch(children: &[(usize, Vec<u8>)], value: &[u8]) -> Vec<u8> {
+    let mut items = vec![rlp::encode_bytes(&[]); 16];
+    for (nibble, child) in children {
+        items[*nibble] = child.clone();
+    }
+    items.push(rlp::encode_bytes(value));
+    rlp::encode_list(&items)
+}
+
+#[test]
+fn test_rlp_round_trip() {
+    for len in [0, 1, 2, 55, 56, 255, 256, 1024] {
+        let bytes = vec![0xab; len];
+        let encoded = rlp::encode_bytes(&bytes);
+        let item = rlp::decode(&encoded).unwrap();
+        assert!(!item.is_list);
+        assert_eq!(item.payload(), bytes);
+        assert_eq!(item.encoded(), encoded);
+    }
+    for byte in [0x00, 0x7f, 0x80, 0xff] {
+        let encoded = rlp::encode_bytes(&[byte]);
+        assert_eq!(encoded.len(), if byte < 0x80 { 1 } else { 2 });
+        assert_eq!(rlp::decode(&encoded).unwrap().payload(), [byte]);
+    }
+
+    let items = (0..20).map(|index| rlp::encode_bytes(&vec![index; index as usize])).collect::<Vec<_>>();
+    let encoded = rlp::encode_list(&items);
+    let list = rlp::decode(&encoded).unwrap();
+    assert!(list.is_list);
+    let decoded = list.items().unwrap().iter().map(|item| item.encoded().to_vec()).collect::<Vec<_>>();
+    assert_eq!(decoded, items);
+}
+
+#[test]
+fn test_rlp_truncated() {
+    assert_eq!(rlp::decode(&[]).unwrap_err(), RlpError::Truncated(0));
+    assert_eq!(rlp::decode(&[0x83, 1, 2]).unwrap_err(), RlpError::Truncated(0));
+    assert_eq!(rlp::decode(&[0xb8]).unwrap_err(), RlpError::Truncated(0));
+    assert_eq!(rlp::decode(&[&[0xb8, 56][..], &[0; 55]].concat()).unwrap_err(), RlpError::Truncated(0));
+    assert_eq!(rlp::decode(&[0xc3, 0x80]).unwrap_err(), RlpError::Truncated(0));
+    // A length that overflows usize.
+    assert_eq!(
+        rlp::decode(&[0xbf, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]).unwrap_err(),
+        RlpError::Truncated(0)
+    );
+
+    // An item of the list ending past the end of the list.
+    let list = rlp::decode(&[0xc2, 0x83, 1]).unwrap();
+    assert_eq!(list.items().unwrap_err(), RlpError::Truncated(1));
+}
+
+#[test]
+fn test_rlp_non_canonical() {
+    assert_eq!(rlp::decode(&[0x81, 0x05]).unwrap_err(), RlpError::NonCanonical(0));
+    assert_eq!(rlp::decode(&[0xb8, 5, 1, 2, 3, 4, 5]).unwrap_err(), RlpError::NonCanonical(0));
+    assert_eq!(
+        rlp::decode(&[&[0xb9, 0, 56][..], &[0; 56]].concat()).unwrap_err(),
+        RlpError::NonCanonical(0)
+    );
+    assert_eq!(rlp::decode(&[0xf8, 1, 0x80]).unwrap_err(), RlpError::NonCanonical(0));
+
+    let list = rlp::decode(&[0xc3, 0x80, 0x81, 0x05]).unwrap();
+    assert_eq!(list.items().unwrap_err(), RlpError::NonCanonical(2));
+}
+
+#[test]
+fn test_rlp_trailing_bytes() {
+    assert_eq!(rlp::decode(&[0x80, 0x80]).unwrap_err(), RlpError::TrailingBytes(1));
+    assert_eq!(rlp::decode(&[0xc1, 0x80, 0x80]).unwrap_err(), RlpError::TrailingBytes(2));
+}
+
+#[test]
+fn test_leaf_and_extension() {
+    assert_eq!(
+        Node::decode(&leaf(&[0x20, 0x12], b"value")).unwrap(),
+        Node::Leaf {
+            path: vec![1, 2],
+            value: b"value".to_vec()
+        }
+    );
+    assert_eq!(
+        Node::decode(&leaf(&[0x31, 0x23], b"value")).unwrap(),
+        Node::Leaf {
+            path: vec![1, 2, 3],
+            value: b"value".to_vec()
+        }
+    );
+    assert_eq!(
+        Node::decode(&leaf(&[0x20], b"v")).unwrap(),
+        Node::Leaf {
+            path: vec![],
+            value: b"v".to_vec()
+        }
+    );
+
+    let hash = [7u8; 32];
+    assert_eq!(
+        Node::decode(&leaf(&[0x00, 0xab], &hash)).unwrap(),
+        Node::Extension {
+            path: vec![0xa, 0xb],
+            child: Child::Hash(hash)
+        }
+    );
+    assert_eq!(
+        Node::decode(&leaf(&[0x1c], &hash)).unwrap(),
+        Node::Extension {
+            path: vec![0xc],
+            child: Child::Hash(hash)
+        }
+    );
+}
+
+#[test]
+fn test_malformed_hex_prefix() {
+    assert_eq!(Node::decode(&leaf(&[], b"value")).unwrap_err(), NodeError::EmptyPath(1));
+    for flag in 4..16 {
+        assert_eq!(
+            Node::decode(&leaf(&[flag << 4 | 1], b"value")).unwrap_err(),
+            NodeError::InvalidPathFlag { position: 1, flag }
+        );
+    }
+    for (encoded_path, nibble) in [([0x21], 1), ([0x0f], 0xf)] {
+        assert_eq!(
+            Node::decode(&leaf(&encoded_path, &[5; 32])).unwrap_err(),
+            NodeError::InvalidPathPadding { position: 1, nibble }
+        );
+    }
+
+    let list_path = rlp::encode_list(&[rlp::encode_list(&[rlp::encode_bytes(&[0x20])]), rlp::encode_bytes(b"value")]);
+    assert_eq!(Node::decode(&list_path).unwrap_err(), NodeError::ExpectedString(1));
+}
+
+#[test]
+fn test_malformed_nodes() {
+    assert_eq!(Node::decode(&rlp::encode_bytes(b"node")).unwrap_err(), NodeError::NotAList(0));
+    assert_eq!(Node::decode(&[0xc1]).unwrap_err(), NodeError::Rlp(RlpError::Truncated(0)));
+    assert_eq!(
+        Node::decode(&rlp::encode_list(&vec![rlp::encode_bytes(&[0x20]); 3])).unwrap_err(),
+        NodeError::InvalidItemCount { position: 0, count: 3 }
+    );
+    assert_eq!(Node::decode(&leaf(&[0x20, 0x12], &[])).unwrap_err(), NodeError::EmptyValue(4));
+    assert_eq!(Node::decode(&leaf(&[0x00, 0x12], &[])).unwrap_err(), NodeError::EmptyExtensionChild(4));
+    assert_eq!(
+        Node::decode(&leaf(&[0x00, 0x12], &[1; 31])).unwrap_err(),
+        NodeError::InvalidChildLength { position: 4, len: 31 }
+    );
+    assert_eq!(
+        Node::decode(&branch(&[(2, rlp::encode_bytes(&[1; 33]))], &[])).unwrap_err(),
+        NodeError::InvalidChildLength { position: 3, len: 33 }
+    );
+
+    let list_value = rlp::encode_list(&[vec![vec![0x80]; 16], vec![rlp::encode_list(&[])]].concat());
+    assert_eq!(Node::decode(&list_value).unwrap_err(), NodeError::ExpectedString(17));
+}
+
+#[test]
+fn test_embedded_nodes() {
+    let embedded_leaf = leaf(&[0x3a], b"short");
+    assert!(embedded_leaf.len() < 32);
+    let node = branch(&[(3, embedded_leaf.clone()), (9, rlp::encode_bytes(&[9; 32]))], b"branch");
+    assert_eq!(
+        Node::decode(&node).unwrap(),
+        Node::Branch {
+            children: Box::new(std::array::from_fn(|nibble| match nibble {
+                3 => Child::Embedded(Box::new(Node::Leaf {
+                    path: vec![0xa],
+                    value: b"short".to_vec()
+                })),
+                9 => Child::Hash([9; 32]),
+                _ => Child::Empty,
+            })),
+            value: Some(b"branch".to_vec()),
+        }
+    );
+
+    let embedded_branch = branch(&[(1, embedded_leaf)], &[]);
+    assert!(embedded_branch.len() < 32);
+    let extension = rlp::encode_list(&[rlp::encode_bytes(&[0x00, 0x12]), embedded_branch]);
+    assert!(matches!(
+        Node::decode(&extension).unwrap(),
+        Node::Extension { path, child: Child::Embedded(branch) } if path == [1, 2] && matches!(*branch, Node::Branch { .. })
+    ));
+
+    // A node of 32 bytes or more must be referenced by hash instead.
+    let oversized_leaf = leaf(&[0x3a], &[0xcc; 29]);
+    assert_eq!(oversized_leaf.len(), 32);
+    assert_eq!(
+        Node::decode(&branch(&[(0, oversized_leaf)], &[])).unwrap_err(),
+        NodeError::OversizedEmbeddedNode { position: 1, len: 32 }
+    );
+}
+
+#[test]
+fn test_embedded_nodes_of_trie() {
+    let mut trie = Trie::new();
+    for index in 0..20 {
+        trie.insert(&key_from_index(index), vec![index as u8 + 1]);
+    }
+    let proof = trie.get_proof(&key_from_index(5));
+    let embedded = proof
+        .iter()
+        .map(|node| Node::decode(node).unwrap())
+        .flat_map(|node| match node {
+            Node::Branch { children, .. } => children.into_iter().collect::<Vec<_>>(),
+            Node::Extension { child, .. } => vec![child],
+            Node::Leaf { .. } => vec![],
+        })
+        .filter(|child| matches!(child, Child::Embedded(_)))
+        .count();
+    assert!(embedded > 0);
+}
+
+#[test]
+fn test_decode_proof() {
+    let nodes = [leaf(&[0x20, 0x12], b"value"), leaf(&[0x40], b"value")];
+    let chunks = nodes.iter().map(|node| bytes_to_le_chunks(node)).collect::<Vec<_>>();
+    let lengths = nodes.iter().map(Vec::len).collect::<Vec<_>>();
+
+    assert_eq!(
+        Node::decode_proof(&chunks, &lengths[..1]).unwrap_err(),
+        MptError::LengthMismatch { nodes: 2, lengths: 1 }
+    );
+    assert_eq!(
+        Node::decode_proof(&chunks, &lengths).unwrap_err(),
+        MptError::MalformedNode {
+            node_index: 1,
+            source: NodeError::InvalidPathFlag { position: 1, flag: 4 }
+        }
+    );
+    assert_eq!(Node::decode_le_chunks(&chunks[0], 100).unwrap_err(), NodeError::MissingChunks(100));
+    assert_eq!(Node::decode_proof(&chunks[..1], &lengths[..1]).unwrap().len(), 1);
+}
@@ -0,0 +1,178 @@
+use crate::mpt::{empty_root, hashed_key, rlp, verify_account_proof, verify_storage_proof, Account, MptError, StorageProofError, Trie, Uint256};
+
+const CODE_HASH: [u8; 32] = [0xcd; 32];
+
+fn address(index: u8) -> Vec<u8> {
+    vec![index; 20]
+}
+
+fn slot(index: u64) -> [u8; 32] {
+    let mut slot = [0u8; 32];
+    slot[24..].copy_from_slice(&index.to_be_bytes());
+    slot
+}
+
+fn slot_value(index: u64) -> u128 {
+    (index as u128 + 1) << (index % 100)
+}
+
+fn encode_account(account: &Account) -> Vec<u8> {
+    rlp::encode_list(&[
+        rlp::encode_uint(account.nonce),
+        rlp::encode_bytes(rlp::trim_leading_zeroes(&account.balance.to_be_bytes())),
+        rlp::encode_bytes(&account.storage_root),
+        rlp::encode_bytes(&account.code_hash),
+    ])
+}
+
+/// The storage of the account at `address(index)`, holding `5 * index` slots.
+fn storage(index: u8) -> Trie {
+    let mut storage = Trie::new();
+    for slot_index in 0..5 * index as u64 {
+        let value = rlp::encode_bytes(rlp::trim_leading_zeroes(&slot_value(slot_index).to_be_bytes()));
+        storage.insert(&hashed_key(&slot(slot_index)), value);
+    }
+    storage
+}
+
+fn account(index: u8) -> Account {
+    Account {
+        nonce: index as u64 * 3,
+        balance: Uint256 {
+            low: index as u128 * 1_000_000_007,
+            high: 0,
+        },
+        storage_root: storage(index).root(),
+        code_hash: CODE_HASH,
+    }
+}
+
+fn state() -> Trie {
+    let mut state = Trie::new();
+    for index in 0..12 {
+        state.insert(&hashed_key(&address(index)), encode_account(&account(index)));
+    }
+    state
+}
+
+#[test]
+fn test_account_proofs() {
+    let state = state();
+    for index in 0..12 {
+        let proof = state.get_proof(&hashed_key(&address(index)));
+        assert_eq!(verify_account_proof(state.root(), &address(index), &proof), Ok(Some(account(index))));
+    }
+    assert_eq!(account(0).storage_root, empty_root());
+}
+
+#[test]
+fn test_storage_proofs() {
+    let state = state();
+    for index in 0..12 {
+        let account_proof = state.get_proof(&hashed_key(&address(index)));
+        let storage = storage(index);
+        for slot_index in 0..5 * index as u64 {
+            let storage_proof = storage.get_proof(&hashed_key(&slot(slot_index)));
+            let expected = Uint256 {
+                low: slot_value(slot_index),
+                high: 0,
+            };
+            assert_eq!(
+                verify_storage_proof(state.root(), &address(index), &account_proof, slot(slot_index), &storage_proof),
+                Ok(expected)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_missing_slots_and_accounts() {
+    let state = state();
+    let account_proof = state.get_proof(&hashed_key(&address(11)));
+    let storage = storage(11);
+    let mut n_verified = 0;
+    for slot_index in 1000..1020 {
+        let storage_proof = storage.get_proof(&hashed_key(&slot(slot_index)));
+        match verify_storage_proof(state.root(), &address(11), &account_proof, slot(slot_index), &storage_proof) {
+            Ok(value) => {
+                assert_eq!(value, Uint256::default());
+                n_verified += 1;
+            }
+            // lib/mpt.cairo rejects some valid non-inclusion proofs, see `resolve_value`.
+            Err(StorageProofError::Storage(MptError::NonEmptyBranchChild(_))) => {}
+            Err(error) => panic!("slot {slot_index}: {error}"),
+        }
+    }
+    assert!(n_verified > 0);
+
+    // The storage of an account that does not exist is empty.
+    let missing = (100..120)
+        .map(|index| (address(index), state.get_proof(&hashed_key(&address(index)))))
+        .find(|(address, proof)| verify_account_proof(state.root(), address, proof) == Ok(None))
+        .unwrap();
+    assert_eq!(
+        verify_storage_proof(state.root(), &missing.0, &missing.1, slot(0), &[]),
+        Ok(Uint256::default())
+    );
+    // The empty storage of an existing account.
+    let account_proof = state.get_proof(&hashed_key(&address(0)));
+    assert_eq!(
+        verify_storage_proof(state.root(), &address(0), &account_proof, slot(0), &[]),
+        Ok(Uint256::default())
+    );
+}
+
+#[test]
+fn test_failing_level() {
+    let state = state();
+    let account_proof = state.get_proof(&hashed_key(&address(7)));
+    let storage_proof = storage(7).get_proof(&hashed_key(&slot(3)));
+
+    let mut wrong_account_proof = account_proof.clone();
+    *wrong_account_proof[1].last_mut().unwrap() ^= 1;
+    assert_eq!(
+        verify_storage_proof(state.root(), &address(7), &wrong_account_proof, slot(3), &storage_proof),
+        Err(StorageProofError::Account(MptError::HashMismatch(1)))
+    );
+
+    let mut wrong_storage_proof = storage_proof.clone();
+    *wrong_storage_proof.last_mut().unwrap().last_mut().unwrap() ^= 1;
+    assert_eq!(
+        verify_storage_proof(state.root(), &address(7), &account_proof, slot(3), &wrong_storage_proof),
+        Err(StorageProofError::Storage(MptError::HashMismatch(wrong_storage_proof.len() - 1)))
+    );
+
+    // The storage proof of another account.
+    let other_storage_proof = storage(8).get_proof(&hashed_key(&slot(3)));
+    assert_eq!(
+        verify_storage_proof(state.root(), &address(7), &account_proof, slot(3), &other_storage_proof),
+        Err(StorageProofError::Storage(MptError::HashMismatch(0)))
+    );
+}
+
+#[test]
+fn test_invalid_values() {
+    let mut state = state();
+    state.insert(&hashed_key(&address(200)), rlp::encode_bytes(&[0xee; 40]));
+    let proof = state.get_proof(&hashed_key(&address(200)));
+    assert_eq!(
+        verify_account_proof(state.root(), &address(200), &proof),
+        Err(StorageProofError::InvalidAccount)
+    );
+
+    let mut storage = storage(2);
+    storage.insert(&hashed_key(&slot(50)), rlp::encode_list(&[rlp::encode_uint(1)]));
+    storage.insert(&hashed_key(&slot(51)), rlp::encode_bytes(&[0xee; 33]));
+    let mut account = account(2);
+    account.storage_root = storage.root();
+    let mut state = Trie::new();
+    state.insert(&hashed_key(&address(2)), encode_account(&account));
+    let account_proof = state.get_proof(&hashed_key(&address(2)));
+    for slot_index in [50, 51] {
+        let storage_proof = storage.get_proof(&hashed_key(&slot(slot_index)));
+        assert_eq!(
+            verify_storage_proof(state.root(), &address(2), &account_proof, slot(slot_index), &storage_proof),
+            Err(StorageProofError::InvalidSlotValue)
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use crate::mpt::{verify_proof, MptError, MptProof, ProofKind, Trie};
+use serde::Deserialize;
+
+/// Tries along with proofs in the `MptProof` format of `tests/rust`, generated with alloy-trie for
+/// keys in and out of the trie. Short values make embedded nodes, which are left out of the proofs
+/// as `eth_trie` does in `tests/rust`.
+#[derive(Deserialize)]
+struct Fixture {
+    #[serde(with = "hex_pairs")]
+    entries: Entries,
+    proofs: Vec<MptProof>,
+}
+
+/// Keys and values of a trie.
+type Entries = Vec<(Vec<u8>, Vec<u8>)>;
+
+mod hex_pairs {
+    use serde::{de::Error, Deserialize, Deserializer};
+
+    use super::Entries;
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Entries, D::Error> {
+        Vec::<[String; 2]>::deserialize(deserializer)?
+            .iter()
+            .map(|[key, value]| Ok((hex::decode(key).map_err(D::Error::custom)?, hex::decode(value).map_err(D::Error::custom)?)))
+            .collect()
+    }
+}
+
+fn fixtures() -> Vec<Fixture> {
+    serde_json::from_str(include_str!("fixtures/trie_proofs.json")).unwrap()
+}
+
+#[test]
+fn test_proofs_match_fixtures() {
+    for fixture in fixtures() {
+        let mut trie = Trie::new();
+        for (key, value) in &fixture.entries {
+            trie.insert(key, value.clone());
+        }
+        for expected in &fixture.proofs {
+            assert_eq!(trie.get_mpt_proof(&expected.key, expected.kind), *expected);
+        }
+    }
+}
+
+#[test]
+fn test_fixture_proofs_verify() {
+    for fixture in fixtures() {
+        for proof in &fixture.proofs {
+            let value = fixture.entries.iter().find(|(key, _)| *key == proof.key).map(|(_, value)| value.clone());
+            match verify_proof(proof.root, &proof.key, &proof.proof) {
+                Ok(verified) => assert_eq!(verified, value),
+                // Like lib/mpt.cairo, the verifier does not follow embedded nodes, and reads the prefix of
+                // the next item at an empty branch child.
+                Err(MptError::UnsupportedItemPrefix { .. }) => assert_eq!(proof.kind, ProofKind::TxProof),
+                Err(MptError::NonEmptyBranchChild(_)) => assert_eq!(value, None),
+                Err(error) => panic!("key {}: {error}", hex::encode(&proof.key)),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_insertion_order() {
+    let fixture = fixtures().pop().unwrap();
+    let mut forward = Trie::new();
+    let mut backward = Trie::new();
+    for (key, value) in &fixture.entries {
+        forward.insert(key, value.clone());
+    }
+    for (key, value) in fixture.entries.iter().rev() {
+        backward.insert(key, value.clone());
+    }
+    assert_eq!(forward.root(), backward.root());
+    assert_eq!(forward.root(), fixture.proofs[0].root);
+}
+
+#[test]
+fn test_remove() {
+    let fixture = fixtures().pop().unwrap();
+    let mut trie = Trie::new();
+    for (key, value) in &fixture.entries {
+        trie.insert(key, value.clone());
+    }
+    let (key, value) = &fixture.entries[7];
+    trie.insert(&[key.as_slice(), &[0]].concat(), vec![1]);
+    trie.insert(&[key.as_slice(), &[0]].concat(), vec![]);
+    assert_eq!(trie.root(), fixture.proofs[0].root);
+
+    trie.insert(key, vec![]);
+    assert_eq!(trie.get(key), None);
+    assert_ne!(trie.root(), fixture.proofs[0].root);
+    trie.insert(key, value.clone());
+    assert_eq!(trie.root(), fixture.proofs[0].root);
+}
+
+#[test]
+fn test_empty_trie() {
+    let trie = Trie::new();
+    assert_eq!(trie.root(), crate::mpt::empty_root());
+    assert!(trie.get_proof(&[1, 2, 3]).is_empty());
+}
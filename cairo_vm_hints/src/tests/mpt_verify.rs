@@ -0,0 +1,134 @@
+use super::run_cairo_program_with_input;
+use crate::hint_processor::ProgramInput;
+use crate::mpt::{bytes_to_le_chunks, hashed_key, keccak256, key_from_index, rlp, verify_mpt_proof, MptError, MptValue, Trie, Uint256};
+use serde_json::json;
+
+const PROGRAM: &[u8] = include_bytes!("../../../build/compiled_cairo_files/verify_mpt_proof.json");
+
+fn verify(root: [u8; 32], key: &[u8], proof: &[Vec<u8>]) -> Result<MptValue, MptError> {
+    let mut key_be = [0u8; 32];
+    key_be[32 - key.len()..].copy_from_slice(key);
+    verify_mpt_proof(
+        &proof.iter().map(|node| bytes_to_le_chunks(node)).collect::<Vec<_>>(),
+        &proof.iter().map(Vec::len).collect::<Vec<_>>(),
+        Uint256::from_be_bytes(key_be),
+        hex::encode(key).chars().take_while(|char| *char == '0').count(),
+        Uint256::from_le_bytes(root),
+    )
+}
+
+/// Runs the proof through `verify_mpt_proof` and through `lib/mpt.cairo`, asserting that both
+/// accept it with the same value or both reject it.
+fn verify_both(root: [u8; 32], key: &[u8], proof: &[Vec<u8>]) -> Result<MptValue, MptError> {
+    let key_hex = hex::encode(key);
+    let result = verify(root, key, proof);
+
+    let program_input = json!({
+        "proof": proof.iter().map(hex::encode).collect::<Vec<_>>(),
+        "root": format!("0x{}", hex::encode(root)),
+        "key": format!("0x{key_hex}"),
+    });
+    let cairo_result = run_cairo_program_with_input(PROGRAM, ProgramInput::new(program_input));
+    match (&result, cairo_result) {
+        (Ok(value), Ok(mut runner)) => {
+            let mut output = String::new();
+            runner.vm.write_output(&mut output).unwrap();
+            let expected = std::iter::once(value.value_len.to_string())
+                .chain(value.value.iter().map(u64::to_string))
+                .collect::<Vec<_>>();
+            assert_eq!(output.lines().collect::<Vec<_>>(), expected, "key 0x{key_hex}");
+        }
+        (Err(_), Err(_)) => {}
+        (result, cairo_result) => panic!(
+            "key 0x{key_hex}: Rust returned {result:?}, Cairo {}",
+            cairo_result.map_or_else(|error| format!("failed with {error}"), |_| "succeeded".to_string())
+        ),
+    }
+    result
+}
+
+#[test]
+fn test_hashed_keys() {
+    let mut trie = Trie::new();
+    for slot in 0u64..40 {
+        trie.insert(&hashed_key(&slot.to_be_bytes()), rlp::encode_uint(slot * 1_000_003 + 1));
+    }
+    let root = trie.root();
+
+    for slot in 0u64..40 {
+        let key = hashed_key(&slot.to_be_bytes());
+        let value = verify_both(root, &key, &trie.get_proof(&key)).unwrap();
+        assert_eq!(value.bytes().as_deref(), trie.get(&key));
+    }
+    for slot in 40u64..80 {
+        let key = hashed_key(&slot.to_be_bytes());
+        if let Ok(value) = verify_both(root, &key, &trie.get_proof(&key)) {
+            assert!(!value.is_included());
+        }
+    }
+}
+
+#[test]
+fn test_index_keys() {
+    let mut trie = Trie::new();
+    for index in 0u64..130 {
+        trie.insert(&key_from_index(index), vec![index as u8; 1 + index as usize % 48]);
+    }
+    let root = trie.root();
+
+    for index in 0u64..140 {
+        let key = key_from_index(index);
+        let _ = verify_both(root, &key, &trie.get_proof(&key));
+    }
+}
+
+#[test]
+fn test_wrong_root() {
+    let mut trie = Trie::new();
+    for slot in 0u64..10 {
+        trie.insert(&hashed_key(&slot.to_be_bytes()), rlp::encode_uint(slot + 1));
+    }
+    let key = hashed_key(&3u64.to_be_bytes());
+    let mut root = trie.root();
+    root[31] ^= 1;
+
+    assert_eq!(verify_both(root, &key, &trie.get_proof(&key)), Err(MptError::HashMismatch(0)));
+}
+
+#[test]
+fn test_empty_value() {
+    let key = hashed_key(b"empty");
+    let leaf = rlp::encode_list(&[rlp::encode_bytes(&[&[0x20], key.as_slice()].concat()), rlp::encode_bytes(&[])]);
+
+    assert_eq!(verify_both(keccak256(&leaf), &key, &[leaf]), Err(MptError::EmptyValue(0)));
+}
+
+#[test]
+fn test_list_prefix_offset() {
+    let key = hashed_key(b"offset");
+    // Long lists whose length takes 7 or 8 bytes, so that the first item starts past the first chunk.
+    for prefix in [0xfe, 0xff] {
+        let len_len = prefix - 0xf7;
+        let mut node = vec![prefix];
+        node.resize(len_len as usize, 0);
+        node.extend([2, 0x80, 0x80]);
+
+        assert_eq!(
+            verify_both(keccak256(&node), &key, &[node]),
+            Err(MptError::InvalidListPrefix { node_index: 0, prefix })
+        );
+    }
+}
+
+#[test]
+fn test_oversized_item_length() {
+    let key = hashed_key(b"oversized");
+    // A leaf whose value claims a length of 8 bytes, which overflows its end offset.
+    let mut node = vec![0xcc, 0x82, 0x20, 0x12, 0xbf];
+    node.extend([0xff; 8]);
+
+    assert_eq!(
+        verify(keccak256(&node), &key, &[node]),
+        Err(MptError::OutOfBounds { node_index: 0, position: 4 })
+    );
+}
@@ -0,0 +1,99 @@
+use crate::hint_processor::ProgramInput;
+use crate::mpt::Uint256;
+use cairo_vm::{vm::errors::hint_errors::HintError, Felt252};
+use serde_json::json;
+
+const U128_MAX: &str = "340282366920938463463374607431768211455";
+const U256_MAX: &str = "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff";
+
+fn message<T: std::fmt::Debug>(result: Result<T, HintError>) -> String {
+    result.unwrap_err().to_string()
+}
+
+#[test]
+fn test_felts() {
+    let program_input = ProgramInput::new(json!({
+        "number": 12,
+        "decimal": "340282366920938463463374607431768211456",
+        "hex": "0x1f",
+        "felts": [1, "2", "0x3"],
+        "nested": [[1, "0x2"], [], ["3"]],
+    }));
+    assert_eq!(program_input.felt("number").unwrap(), Felt252::from(12));
+    assert_eq!(program_input.felt("decimal").unwrap(), Felt252::from(u128::MAX) + 1);
+    assert_eq!(program_input.felt("hex").unwrap(), Felt252::from(0x1f));
+    assert_eq!(
+        program_input.felts("felts").unwrap(),
+        vec![Felt252::from(1), Felt252::from(2), Felt252::from(3)]
+    );
+    assert_eq!(
+        program_input.nested_felts("nested").unwrap(),
+        vec![vec![Felt252::from(1), Felt252::from(2)], vec![], vec![Felt252::from(3)]]
+    );
+}
+
+#[test]
+fn test_uint256s() {
+    let expected = Uint256 { low: 1, high: 2 };
+    let program_input = ProgramInput::new(json!({
+        "pair": [1, "0x2"],
+        "object": { "low": "1", "high": 2 },
+        "number": "0x200000000000000000000000000000001",
+        "decimal": U128_MAX,
+        "max": U256_MAX,
+        "uint256s": [[1, 2], { "low": 1, "high": 2 }, "680564733841876926926749214863536422913"],
+    }));
+    assert_eq!(program_input.uint256("pair").unwrap(), expected);
+    assert_eq!(program_input.uint256("object").unwrap(), expected);
+    assert_eq!(program_input.uint256("number").unwrap(), expected);
+    assert_eq!(program_input.uint256("decimal").unwrap(), Uint256 { low: u128::MAX, high: 0 });
+    assert_eq!(
+        program_input.uint256("max").unwrap(),
+        Uint256 {
+            low: u128::MAX,
+            high: u128::MAX
+        }
+    );
+    assert_eq!(program_input.uint256s("uint256s").unwrap(), vec![expected; 3]);
+}
+
+#[test]
+fn test_missing_key() {
+    let program_input = ProgramInput::new(json!({ "felt": 1 }));
+    assert_eq!(message(program_input.felt("other")), "Hint Error: Missing program input: other");
+    assert_eq!(message(program_input.uint256s("other")), "Hint Error: Missing program input: other");
+    assert_eq!(message(ProgramInput::default().felt("felt")), "Hint Error: Missing program input: felt");
+}
+
+#[test]
+fn test_rejections() {
+    let program_input = ProgramInput::new(json!({
+        "object": { "low": 1 },
+        "array": [1, 2],
+        "nested": [[1], 2],
+        "invalid": "1x",
+        "negative": -1,
+        "too_large": "0x10000000000000000000000000000000000000000000000000000000000000000",
+        "large_limb": [format!("0x1{}", "0".repeat(32)), 0],
+        "three_limbs": [1, 2, 3],
+        "uint256s": [[1, 2], 3.5],
+    }));
+    let invalid = |key: &str, expected: &str| format!("Hint Error: Program input {key} is not {expected}");
+
+    assert_eq!(message(program_input.felt("object")), invalid("object", "a felt"));
+    assert_eq!(message(program_input.felt("array")), invalid("array", "a felt"));
+    assert_eq!(message(program_input.felt("invalid")), invalid("invalid", "a felt"));
+    assert_eq!(message(program_input.felts("object")), invalid("object", "a felt array"));
+    assert_eq!(message(program_input.felts("nested")), invalid("nested", "a felt array"));
+    assert_eq!(message(program_input.nested_felts("array")), invalid("array", "an array of felt arrays"));
+    assert_eq!(
+        message(program_input.nested_felts("nested")),
+        invalid("nested", "an array of felt arrays")
+    );
+
+    for key in ["object", "invalid", "negative", "too_large", "large_limb", "three_limbs"] {
+        assert_eq!(message(program_input.uint256(key)), invalid(key, "a Uint256"));
+    }
+    assert_eq!(message(program_input.uint256s("object")), invalid("object", "a Uint256 array"));
+    assert_eq!(message(program_input.uint256s("uint256s")), invalid("uint256s", "a Uint256 array"));
+}
@@ -0,0 +1,130 @@
+use crate::hint_processor::{builtin_hints::BUILTIN_HINT_CODES, HintModule, UnknownHint};
+use crate::hints::EthEssentialsHints;
+use crate::ExtendedHintProcessor;
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::{
+            builtin_hint_processor_definition::{BuiltinHintProcessor, HintFunc, HintProcessorData},
+            hint_code,
+        },
+        hint_processor_definition::HintProcessorLogic,
+    },
+    serde::deserialize_program::ProgramJson,
+    types::{exec_scope::ExecutionScopes, relocatable::Relocatable},
+    vm::{context::run_context::RunContext, errors::hint_errors::HintError, vm_core::VirtualMachineBuilder},
+};
+use serde_json::{json, Value};
+use std::{any::Any, collections::HashMap, rc::Rc};
+
+fn hint(code: &str) -> Value {
+    json!({
+        "code": code,
+        "accessible_scopes": ["__main__"],
+        "flow_tracking_data": { "ap_tracking": { "group": 0, "offset": 0 }, "reference_ids": {} },
+    })
+}
+
+/// The location of an instruction of `main.cairo` at `line`, with one location per hint.
+fn instruction_location(line: u32, n_hints: usize) -> Value {
+    let location = json!({
+        "start_line": line,
+        "start_col": 5,
+        "end_line": line,
+        "end_col": 20,
+        "input_file": { "filename": "main.cairo" },
+    });
+    json!({
+        "inst": location,
+        "hints": vec![json!({ "location": location, "n_prefix_newlines": 0 }); n_hints],
+    })
+}
+
+fn program(hints: Value, instruction_locations: Value) -> ProgramJson {
+    serde_json::from_value(json!({
+        "prime": "0x800000000000011000000000000000000000000000000000000000000000001",
+        "builtins": [],
+        "data": ["0x1", "0x1", "0x1", "0x1", "0x1", "0x1", "0x1", "0x1", "0x1", "0x1"],
+        "identifiers": {},
+        "hints": hints,
+        "reference_manager": { "references": [] },
+        "debug_info": { "instruction_locations": instruction_locations },
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_unknown_hints_with_locations() {
+    let (registered, _) = EthEssentialsHints.hints()[0];
+    let program = program(
+        json!({
+            "0": [hint(BUILTIN_HINT_CODES[0]), hint("first_unknown()")],
+            "2": [hint("second_unknown()")],
+            "4": [hint(registered), hint("first_unknown()")],
+            "8": [hint("second_unknown()")],
+        }),
+        json!({
+            "0": instruction_location(3, 2),
+            "2": instruction_location(7, 1),
+            "4": instruction_location(12, 2),
+        }),
+    );
+
+    let report = ExtendedHintProcessor::new().unknown_hints(&program);
+    assert_eq!(
+        report.hints,
+        [
+            UnknownHint {
+                code: "first_unknown()".to_string(),
+                locations: vec!["main.cairo:3:5".to_string(), "main.cairo:12:5".to_string()],
+            },
+            UnknownHint {
+                code: "second_unknown()".to_string(),
+                locations: vec!["main.cairo:7:5".to_string(), "pc 8".to_string()],
+            },
+        ]
+    );
+    assert_eq!(
+        report.to_string(),
+        "main.cairo:3:5:\nmain.cairo:12:5:\n    first_unknown()\nmain.cairo:7:5:\npc 8:\n    second_unknown()\n"
+    );
+}
+
+#[test]
+fn test_known_hints() {
+    let mut hint_processor = ExtendedHintProcessor::new();
+    assert!(BUILTIN_HINT_CODES.iter().all(|code| hint_processor.supports(code)));
+    assert!(EthEssentialsHints.hints().iter().all(|(code, _)| hint_processor.supports(code)));
+    assert!(!hint_processor.supports("first_unknown()"));
+
+    // Builtin hints are matched exactly.
+    assert!(!hint_processor.supports(&format!("{}\n", BUILTIN_HINT_CODES[0])));
+
+    let program = program(json!({ "0": [hint("first_unknown()")] }), json!({}));
+    assert!(!hint_processor.unknown_hints(&program).is_empty());
+    hint_processor.add_hint("first_unknown()".to_string(), Rc::new(HintFunc(Box::new(|_, _, _, _, _| Ok(())))));
+    assert!(hint_processor.unknown_hints(&program).is_empty());
+}
+
+/// Runs `code` with `BuiltinHintProcessor` on an empty execution segment, with no variables.
+fn run_builtin_hint(code: &str) -> Result<(), HintError> {
+    let mut vm = VirtualMachineBuilder::default()
+        .run_context(RunContext::new(Relocatable::from((0, 0)), 0, 0))
+        .build();
+    vm.segments.add();
+    vm.segments.add();
+    let hint_data: Box<dyn Any> = Box::new(HintProcessorData::new_default(code.to_string(), HashMap::new()));
+    BuiltinHintProcessor::new_empty().execute_hint(&mut vm, &mut ExecutionScopes::new(), &hint_data, &HashMap::new())
+}
+
+#[test]
+fn test_builtin_hints_are_run() {
+    // A code missing from `BuiltinHintProcessor`, as after a cairo-vm upgrade, fails as unknown rather
+    // than for its missing variables.
+    for code in BUILTIN_HINT_CODES {
+        if let Err(HintError::UnknownHint(_)) = run_builtin_hint(code) {
+            panic!("BuiltinHintProcessor does not run {code:?}");
+        }
+    }
+    assert!(run_builtin_hint(hint_code::ADD_SEGMENT).is_ok());
+    assert!(matches!(run_builtin_hint("first_unknown()"), Err(HintError::UnknownHint(_))));
+}
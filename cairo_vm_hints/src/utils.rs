@@ -8,6 +8,10 @@ use cairo_vm::{
     Felt252,
 };
 use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+use starknet_types_core::felt::NonZeroFelt;
+
+use crate::hints::HintValueError;
 
 pub fn split_u256(number: &BigUint) -> [BigUint; 2] {
     let mut iter = number.to_bytes_le().into_iter();
@@ -42,3 +46,31 @@ pub fn write_vector(var_name: &str, vector: &[MaybeRelocatable], vm: &mut Virtua
 pub fn get_value(var_name: &str, vm: &mut VirtualMachine, hint_data: &HintProcessorData) -> Result<Felt252, HintError> {
     get_integer_from_var_name(var_name, vm, &hint_data.ids_data, &hint_data.ap_tracking)
 }
+
+/// Converts the value of `variable` to a `T`, failing with an error naming `hint` if it does not fit.
+pub fn felt_to<T: TryFrom<u128>>(hint: &'static str, variable: &'static str, value: Felt252) -> Result<T, HintValueError> {
+    value
+        .to_u128()
+        .and_then(|value| T::try_from(value).ok())
+        .ok_or(HintValueError::OutOfRange {
+            hint,
+            variable,
+            value,
+            ty: std::any::type_name::<T>(),
+        })
+}
+
+/// Reads `var_name` as a `T`, see `felt_to`.
+pub fn get_value_as<T: TryFrom<u128>>(
+    hint: &'static str,
+    var_name: &'static str,
+    vm: &mut VirtualMachine,
+    hint_data: &HintProcessorData,
+) -> Result<T, HintError> {
+    Ok(felt_to(hint, var_name, get_value(var_name, vm, hint_data)?)?)
+}
+
+/// The value of `variable` as a divisor, failing with an error naming `hint` if it is zero.
+pub fn non_zero(hint: &'static str, variable: &'static str, value: Felt252) -> Result<NonZeroFelt, HintValueError> {
+    NonZeroFelt::try_from(value).map_err(|_| HintValueError::Zero { hint, variable })
+}
@@ -0,0 +1,39 @@
+%builtins output range_check
+
+from starkware.cairo.common.memcpy import memcpy
+
+from lib.block_header import read_block_headers
+from lib.utils import felt_divmod_8
+
+const N_HEADERS = 2;
+
+// Reads the block headers of the program input and outputs, for each of the first N_HEADERS of them,
+// its byte length followed by its 64-bit words.
+// Used to test the read_block_headers hint, see cairo_vm_hints/src/tests/block_headers.rs.
+func main{output_ptr: felt*, range_check_ptr}() {
+    let (rlp_array: felt**, rlp_array_bytes_len: felt*) = read_block_headers();
+    write_headers(rlp_array=rlp_array, rlp_array_bytes_len=rlp_array_bytes_len, n_headers=N_HEADERS);
+
+    return ();
+}
+
+func write_headers{output_ptr: felt*, range_check_ptr}(
+    rlp_array: felt**, rlp_array_bytes_len: felt*, n_headers: felt
+) {
+    alloc_locals;
+    if (n_headers == 0) {
+        return ();
+    }
+
+    local bytes_len = rlp_array_bytes_len[0];
+    let (n_words_, _) = felt_divmod_8(bytes_len + 7);
+    local n_words = n_words_;
+
+    assert [output_ptr] = bytes_len;
+    memcpy(dst=output_ptr + 1, src=rlp_array[0], len=n_words);
+    let output_ptr = output_ptr + 1 + n_words;
+
+    return write_headers(
+        rlp_array=rlp_array + 1, rlp_array_bytes_len=rlp_array_bytes_len + 1, n_headers=n_headers - 1
+    );
+}
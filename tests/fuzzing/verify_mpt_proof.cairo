@@ -0,0 +1,62 @@
+%builtins output range_check bitwise keccak
+
+from starkware.cairo.common.alloc import alloc
+from starkware.cairo.common.cairo_builtins import BitwiseBuiltin, KeccakBuiltin
+from starkware.cairo.common.uint256 import Uint256
+from starkware.cairo.common.memcpy import memcpy
+
+from lib.utils import pow2alloc128, felt_divmod_8
+from lib.mpt import verify_mpt_proof
+
+// Verifies the MPT proof of the program input and outputs value_len followed by the value chunks.
+// For a non inclusion proof, value_len is -1 and the chunks are those of the last node.
+// Used to compare lib/mpt.cairo with the Rust verifier, see cairo_vm_hints/src/tests/mpt_verify.rs.
+func main{
+    output_ptr: felt*, range_check_ptr, bitwise_ptr: BitwiseBuiltin*, keccak_ptr: KeccakBuiltin*
+}() {
+    alloc_locals;
+    let (local proof: felt**) = alloc();
+    let (local proof_bytes_len: felt*) = alloc();
+    local proof_len: felt;
+    local key: Uint256;
+    local key_leading_zeroes: felt;
+    local root: Uint256;
+
+    %{
+        from tools.py.utils import bytes_to_8_bytes_chunks_little, split_128, reverse_endian_256, count_leading_zero_nibbles_from_hex
+
+        proof = [bytes.fromhex(node) for node in program_input["proof"]]
+        segments.write_arg(ids.proof, [bytes_to_8_bytes_chunks_little(node) for node in proof])
+        segments.write_arg(ids.proof_bytes_len, [len(node) for node in proof])
+        ids.proof_len = len(proof)
+
+        (ids.root.low, ids.root.high) = split_128(reverse_endian_256(int(program_input["root"], 16)))
+        ids.key_leading_zeroes = count_leading_zero_nibbles_from_hex(program_input["key"])
+        (ids.key.low, ids.key.high) = split_128(int(program_input["key"], 16))
+    %}
+
+    let (pow2_array: felt*) = pow2alloc128();
+    let (local value: felt*, local value_len: felt) = verify_mpt_proof(
+        mpt_proof=proof,
+        mpt_proof_bytes_len=proof_bytes_len,
+        mpt_proof_len=proof_len,
+        key_be=key,
+        key_be_leading_zeroes_nibbles=key_leading_zeroes,
+        root=root,
+        pow2_array=pow2_array,
+    );
+
+    local value_bytes_len: felt;
+    if (value_len == -1) {
+        assert value_bytes_len = proof_bytes_len[proof_len - 1];
+    } else {
+        assert value_bytes_len = value_len;
+    }
+    let (n_chunks, _) = felt_divmod_8(value_bytes_len + 7);
+
+    assert [output_ptr] = value_len;
+    memcpy(dst=output_ptr + 1, src=value, len=n_chunks);
+    let output_ptr = output_ptr + 1 + n_chunks;
+
+    return ();
+}